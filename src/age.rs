@@ -0,0 +1,177 @@
+use std::fmt;
+
+/// Number of age classes.
+pub const AGE_CLASSES: usize = 4;
+
+/// This enum describes how long a living cell has already survived.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AgeClass {
+    /// Born in this generation.
+    Newborn,
+    /// Survived up to 3 generations.
+    Young,
+    /// Survived up to 31 generations.
+    Stable,
+    /// Survived more than 31 generations, typically part of a still life.
+    Ancient,
+}
+
+impl AgeClass {
+    /// Classifies the age of a cell in generations.
+    pub fn of(age: usize) -> AgeClass {
+        match age {
+            0 => AgeClass::Newborn,
+            1..=3 => AgeClass::Young,
+            4..=31 => AgeClass::Stable,
+            _ => AgeClass::Ancient,
+        }
+    }
+
+    /// Position of this class in an age histogram, from young to old.
+    pub fn get_index(&self) -> usize {
+        match *self {
+            AgeClass::Newborn => 0,
+            AgeClass::Young => 1,
+            AgeClass::Stable => 2,
+            AgeClass::Ancient => 3,
+        }
+    }
+
+    /// Red, green and blue of the colour cells of this class are shown in.
+    fn get_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            AgeClass::Newborn => (135, 255, 0),
+            AgeClass::Young => (255, 255, 0),
+            AgeClass::Stable => (255, 135, 0),
+            AgeClass::Ancient => (175, 0, 215),
+        }
+    }
+
+    /// Index of the colour cells of this class are shown in on terminals with 256 colours.
+    fn get_ansi_256(&self) -> u8 {
+        match *self {
+            AgeClass::Newborn => 118,
+            AgeClass::Young => 226,
+            AgeClass::Stable => 208,
+            AgeClass::Ancient => 128,
+        }
+    }
+}
+
+/// This enum describes which colours the terminal supports.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ColorMode {
+    /// Cells are not coloured.
+    Off,
+    /// The 256 colours palette of ANSI terminals.
+    Ansi256,
+    /// Any colour given by red, green and blue.
+    TrueColor,
+}
+
+impl ColorMode {
+    /// Parses a color mode from its name (`off`, `256` or `truecolor`).
+    pub fn parse(name: &str) -> Result<ColorMode, String> {
+        match name.trim().to_lowercase().as_str() {
+            "off" => Ok(ColorMode::Off),
+            "256" => Ok(ColorMode::Ansi256),
+            "truecolor" => Ok(ColorMode::TrueColor),
+            _ => Err(format!("Unknown color mode '{}'! Expected 'off', '256' or 'truecolor'.", name)),
+        }
+    }
+
+    /// Wraps the given glyph into control sequences, which colour it like cells of the given age
+    /// class.
+    pub fn paint(&self, glyph: &str, class: &AgeClass) -> String {
+        match *self {
+            ColorMode::Off => String::from(glyph),
+            ColorMode::Ansi256 => format!("\x1b[38;5;{}m{}\x1b[0m", class.get_ansi_256(), glyph),
+            ColorMode::TrueColor => {
+                let (red, green, blue) = class.get_rgb();
+                format!("\x1b[38;2;{};{};{}m{}\x1b[0m", red, green, blue, glyph)
+            },
+        }
+    }
+
+    /// Wraps the given glyph into control sequences, which colour it in a grey fading out along
+    /// the trail of the given length.
+    pub fn paint_trail(&self, glyph: &str, decay: usize, trail: usize) -> String {
+        // Fraction of the trail already faded in percent.
+        let faded = decay * 100 / trail.max(1);
+
+        match *self {
+            ColorMode::Off => String::from(glyph),
+            ColorMode::Ansi256 => format!("\x1b[38;5;{}m{}\x1b[0m", 250 - faded * 14 / 100, glyph),
+            ColorMode::TrueColor => {
+                let level = 200 - faded * 140 / 100;
+                format!("\x1b[38;2;{};{};{}m{}\x1b[0m", level, level, level, glyph)
+            },
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ColorMode::Off => write!(f, "off"),
+            ColorMode::Ansi256 => write!(f, "256"),
+            ColorMode::TrueColor => write!(f, "truecolor"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn classify_ages() {
+        assert_that!(AgeClass::of(0), is(equal_to(AgeClass::Newborn)));
+        assert_that!(AgeClass::of(1), is(equal_to(AgeClass::Young)));
+        assert_that!(AgeClass::of(3), is(equal_to(AgeClass::Young)));
+        assert_that!(AgeClass::of(4), is(equal_to(AgeClass::Stable)));
+        assert_that!(AgeClass::of(31), is(equal_to(AgeClass::Stable)));
+        assert_that!(AgeClass::of(32), is(equal_to(AgeClass::Ancient)));
+    }
+
+    #[test]
+    fn parse_known_color_modes() {
+        assert_that!(ColorMode::parse("off"), is(equal_to(Ok(ColorMode::Off))));
+        assert_that!(ColorMode::parse("256"), is(equal_to(Ok(ColorMode::Ansi256))));
+        assert_that!(ColorMode::parse("TrueColor"), is(equal_to(Ok(ColorMode::TrueColor))));
+    }
+
+    #[test]
+    fn parse_unknown_color_mode() {
+        assert_that!(
+            ColorMode::parse("16"),
+            is(equal_to(Err(String::from("Unknown color mode '16'! Expected 'off', '256' or 'truecolor'.")))));
+    }
+
+    #[test]
+    fn paint_glyph() {
+        assert_that!(ColorMode::Off.paint("☀", &AgeClass::Young), is(equal_to(String::from("☀"))));
+        assert_that!(
+            ColorMode::Ansi256.paint("☀", &AgeClass::Newborn),
+            is(equal_to(String::from("\x1b[38;5;118m☀\x1b[0m"))));
+        assert_that!(
+            ColorMode::TrueColor.paint("☀", &AgeClass::Ancient),
+            is(equal_to(String::from("\x1b[38;2;175;0;215m☀\x1b[0m"))));
+    }
+
+    #[test]
+    fn paint_trail_fades_out() {
+        assert_that!(ColorMode::Off.paint_trail("☼", 0, 4), is(equal_to(String::from("☼"))));
+        assert_that!(ColorMode::Ansi256.paint_trail("☼", 0, 4), is(equal_to(String::from("\x1b[38;5;250m☼\x1b[0m"))));
+        assert_that!(ColorMode::Ansi256.paint_trail("·", 3, 4), is(equal_to(String::from("\x1b[38;5;240m·\x1b[0m"))));
+        assert_that!(
+            ColorMode::TrueColor.paint_trail("•", 1, 2),
+            is(equal_to(String::from("\x1b[38;2;130;130;130m•\x1b[0m"))));
+    }
+
+    #[test]
+    fn format_color_mode() {
+        assert_that!(format!("{}", ColorMode::Ansi256), is(equal_to(String::from("256"))));
+    }
+}
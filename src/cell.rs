@@ -1,17 +1,22 @@
 use std::fmt;
 use crate::place::Place;
+use crate::theme::Theme;
 
 /// This struct represents a living cell.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Cell {
     position: Place,
     is_dead: bool,
+    /// Number of generations this cell has survived.
+    age: usize,
+    /// Number of generations this cell has been dead.
+    decay: usize,
 }
 
 impl Cell {
     /// Create a new cell a given position.
     pub fn new(position: Place) -> Cell {
-        Cell { position, is_dead: false }
+        Cell { position, is_dead: false, age: 0, decay: 0 }
     }
 
     /// Get the position of the cell.
@@ -22,7 +27,29 @@ impl Cell {
     /// Creates a copy of this cell with flag dead.
     /// This method does not mutate this cell.
     pub fn kill(&self) -> Cell {
-        Cell { position: self.position.clone(), is_dead: true }
+        Cell { position: self.position.clone(), is_dead: true, age: self.age, decay: 0 }
+    }
+
+    /// Creates a copy of this cell which has survived one more generation.
+    /// This method does not mutate this cell.
+    pub fn grow_older(&self) -> Cell {
+        Cell { age: self.age + 1, ..self.clone() }
+    }
+
+    /// Creates a copy of this dead cell which has been dead for one more generation.
+    /// This method does not mutate this cell.
+    pub fn fade(&self) -> Cell {
+        Cell { decay: self.decay + 1, ..self.clone() }
+    }
+
+    /// Get the number of generations this cell has survived.
+    pub fn get_age(&self) -> usize {
+        self.age
+    }
+
+    /// Get the number of generations this cell has been dead.
+    pub fn get_decay(&self) -> usize {
+        self.decay
     }
 
     /// Whether this cell is dead or alive.
@@ -32,13 +59,13 @@ impl Cell {
     }
 }
 
+/// Number of generations a dead cell is assumed to be shown when formatted on its own.
+const DISPLAY_TRAIL: usize = 5;
+
 impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_dead {
-            write!(f, "☼")
-        } else {
-            write!(f, "☀")
-        }
+        // Without population the cell is shown on a trail long enough for all fade stages.
+        write!(f, "{}", Theme::sun().get_glyph(self, DISPLAY_TRAIL))
     }
 }
 
@@ -60,4 +87,21 @@ mod tests {
 
         assert_that!(format!("{}", sut), is(equal_to(String::from("☀"))));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn format_fading() {
+        let sut = Cell::new(Place::new(1, 1)).kill().fade();
+
+        assert_that!(sut.get_decay(), is(equal_to(1)));
+        assert_that!(format!("{}", sut), is(equal_to(String::from("•"))));
+        assert_that!(format!("{}", sut.fade().fade().fade()), is(equal_to(String::from("·"))));
+    }
+
+    #[test]
+    fn grow_older() {
+        let sut = Cell::new(Place::new(1, 1)).grow_older().grow_older();
+
+        assert_that!(sut.get_age(), is(equal_to(2)));
+        assert_that!(sut.kill().get_age(), is(equal_to(2)));
+    }
+}
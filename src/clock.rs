@@ -0,0 +1,80 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// This trait is implemented by the time source of a game, which measures frames and sleeps
+/// between them.
+pub trait Clock {
+    /// Time passed since the clock was created.
+    fn now(&self) -> Duration;
+
+    /// Waits for the given duration.
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// This struct is the clock of the real world.
+#[derive(Debug, Clone)]
+pub struct SystemClock {
+    started: Instant,
+}
+
+impl SystemClock {
+    /// Creates a clock starting now.
+    pub fn new() -> SystemClock {
+        SystemClock { started: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// This struct is a clock whose time only passes when it sleeps, so runs can be simulated
+/// without waiting.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SimulatedClock {
+    now: Duration,
+}
+
+impl SimulatedClock {
+    /// Creates a clock starting at zero.
+    pub fn new() -> SimulatedClock {
+        SimulatedClock { now: Duration::from_secs(0) }
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> Duration {
+        self.now
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn simulated_clock_passes_time_while_sleeping() {
+        let mut sut = SimulatedClock::new();
+        sut.sleep(Duration::from_millis(30));
+        sut.sleep(Duration::from_millis(12));
+
+        assert_that!(sut.now(), is(equal_to(Duration::from_millis(42))));
+    }
+}
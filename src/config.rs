@@ -1,84 +1,384 @@
 use clap::ArgMatches;
+use crate::age::ColorMode;
+use crate::error::{Error, Reason};
+use crate::image::{ImageFormat, ImageStyle};
+use rand::Rng;
 use std::fmt;
 use std::time;
 use std::time::Duration;
+use crate::pattern::Pattern;
+use crate::render::Render;
+use crate::rule::Rule;
+use crate::theme::Theme;
+use crate::topology::Topology;
 
 /// Default width of the game used if the CLI option is not given.
-pub static DEFAULT_WIDTH: &'static str = "40";
+pub static DEFAULT_WIDTH: &str = "40";
 /// Default height of the game used if the CLI option is not given.
-pub static DEFAULT_HEIGHT: &'static str = "20";
+pub static DEFAULT_HEIGHT: &str = "20";
 /// Default sleep of the game used if the CLI option is not given.
-pub static DEFAULT_SLEEP: &'static str = "1";
+pub static DEFAULT_SLEEP: &str = "1";
+/// Maximum frames per second which can be configured.
+pub static MAX_FPS: u64 = 1000;
 /// Default ratio of the game used if the CLI option is not given.
-pub static DEFAULT_RATIO: &'static str = "4";
+pub static DEFAULT_RATIO: &str = "4";
+/// Default rule of the game used if the CLI option is not given.
+pub static DEFAULT_RULE: &str = "B3/S23";
+/// Default number of generations remembered to detect still lifes and oscillators.
+pub static DEFAULT_HISTORY: &str = "64";
+/// Default topology of the game used if the CLI option is not given.
+pub static DEFAULT_TOPOLOGY: &str = "plane";
+/// Default number of places per character used if the CLI option is not given.
+pub static DEFAULT_ZOOM: &str = "1";
+/// Default render mode used if the CLI option is not given.
+pub static DEFAULT_RENDER: &str = "cells";
+/// Default color mode used if the CLI option is not given.
+pub static DEFAULT_COLOR: &str = "off";
+/// Default number of generations dead cells are shown used if the CLI option is not given.
+pub static DEFAULT_TRAIL: &str = "1";
+/// Default glyph theme used if the CLI option is not given.
+pub static DEFAULT_THEME: &str = "sun";
+/// Default directory snapshots are saved to if the CLI option is not given.
+pub static DEFAULT_SNAPSHOT_DIR: &str = "snapshots";
+/// Default image format of snapshots used if the CLI option is not given.
+pub static DEFAULT_SNAPSHOT_FORMAT: &str = "png";
+/// Default number of pixels per place of snapshots used if the CLI option is not given.
+pub static DEFAULT_SNAPSHOT_SCALE: &str = "4";
+/// Default maximum number of recorded frames used if the CLI option is not given.
+pub static DEFAULT_RECORD_FRAMES: &str = "500";
+/// Default number of pixels per place of recordings used if the CLI option is not given.
+pub static DEFAULT_RECORD_SCALE: &str = "4";
+/// Default file a drawing is saved to if no dump file is given.
+pub static DEFAULT_DRAWING: &str = "drawing.rle";
 
 /// Creates a new config from the arguments matcher.
 /// This function validates the values and throws an error if not met requirements.
-pub fn create_config(matches: &ArgMatches) -> Result<Config, String> {
+pub fn create_config(matches: &ArgMatches) -> Result<Config, Error> {
     let width = matches.value_of("width").unwrap_or(DEFAULT_WIDTH);
     let height = matches.value_of("height").unwrap_or(DEFAULT_HEIGHT);
     let sleep = matches.value_of("sleep").unwrap_or(DEFAULT_SLEEP);
     let ratio = matches.value_of("ratio").unwrap_or(DEFAULT_RATIO);
+    let topology = matches.value_of("topology").unwrap_or(DEFAULT_TOPOLOGY);
+    let history = matches.value_of("history").unwrap_or(DEFAULT_HISTORY);
+    let zoom = matches.value_of("zoom").unwrap_or(DEFAULT_ZOOM);
+    let render = matches.value_of("render").unwrap_or(DEFAULT_RENDER);
+    let color = matches.value_of("color").unwrap_or(DEFAULT_COLOR);
+    let trail = matches.value_of("trail").unwrap_or(DEFAULT_TRAIL);
+    let theme = matches.value_of("theme").unwrap_or(DEFAULT_THEME);
 
-    validate_config(width, height, sleep, ratio)
+    let mut config = validate_config(width, height, sleep, ratio)?
+        .with_topology(validate_topology(topology)?)
+        .with_history(validate_history(history)?)
+        .with_zoom(validate_zoom(zoom)?)
+        .with_render(validate_render(render)?)
+        .with_color(validate_color(color)?)
+        .with_trail(validate_trail(trail)?)
+        .with_theme(validate_theme(theme)?);
+
+    if let Some(glyphs) = matches.value_of("glyphs") {
+        if matches.value_of("theme").is_some() {
+            return Err(error_bad_option_conflict("glyphs", Some(glyphs), "theme"));
+        }
+
+        config = config.with_theme(validate_glyphs(glyphs)?);
+    }
+
+    if matches.is_present("no-border") {
+        let theme = config.theme.clone().without_border();
+        config = config.with_theme(theme);
+    }
+
+    if let Some(fps) = matches.value_of("fps") {
+        if matches.value_of("sleep").is_some() {
+            return Err(error_bad_option_conflict("fps", Some(fps), "sleep"));
+        }
+
+        config = config.with_fps(validate_fps(fps)?);
+    }
+
+    // Without an explicit rule the rule of a given pattern is preferred over the default.
+    if let Some(rule) = matches.value_of("rule") {
+        config = config.with_rule(validate_rule(rule)?);
+    }
+
+    // Without an explicit seed a random one is chosen, so the soup can be recreated later on.
+    let seed = match matches.value_of("seed") {
+        Some(seed) => validate_seed(seed)?,
+        None => rand::thread_rng().next_u64(),
+    };
+    config = config.with_seed(seed);
+
+    if let Some(generations) = matches.value_of("generations") {
+        config = config.with_generations(validate_generation("generations", generations)?);
+    }
+
+    if matches.is_present("headless") {
+        config = config.with_headless();
+    }
+
+    if matches.is_present("interactive") {
+        if config.is_headless() {
+            return Err(error_bad_option_conflict("interactive", None, "headless"));
+        }
+
+        config = config.with_interactive();
+    }
+
+    if matches.is_present("edit") {
+        if config.is_headless() {
+            return Err(error_bad_option_conflict("edit", None, "headless"));
+        }
+
+        config = config.with_edit();
+    }
+
+    if matches.is_present("follow") {
+        config = config.with_follow();
+    }
+
+    if matches.is_present("flicker-free") {
+        if config.is_headless() {
+            return Err(error_bad_option_conflict("flicker-free", None, "headless"));
+        }
+
+        config = config.with_flicker_free();
+    }
+
+    if let Some(pattern) = matches.value_of("pattern") {
+        config = config.with_pattern(String::from(pattern));
+    }
+
+    if let Some(dump) = matches.value_of("dump") {
+        let generation = match matches.value_of("dump-generation") {
+            Some(generation) => Some(validate_generation("dump-generation", generation)?),
+            None => None,
+        };
+
+        config = config.with_dump(String::from(dump), generation);
+    }
+
+    if let Some(every) = matches.value_of("snapshot-every") {
+        let dir = matches.value_of("snapshot-dir").unwrap_or(DEFAULT_SNAPSHOT_DIR);
+        let format = matches.value_of("snapshot-format").unwrap_or(DEFAULT_SNAPSHOT_FORMAT);
+        let scale = matches.value_of("snapshot-scale").unwrap_or(DEFAULT_SNAPSHOT_SCALE);
+        let mut style = ImageStyle::new().with_scale(validate_snapshot_scale(scale)?);
+
+        if matches.is_present("snapshot-grid") {
+            style = style.with_grid();
+        }
+
+        config = config.with_snapshots(
+            validate_snapshot_every(every)?,
+            String::from(dir),
+            validate_snapshot_format(format)?,
+            style);
+    }
+
+    if let Some(record) = matches.value_of("record") {
+        let from = match matches.value_of("record-from") {
+            Some(from) => validate_generation("record-from", from)?,
+            None => 0,
+        };
+        let to = match matches.value_of("record-to") {
+            Some(to) => Some(validate_record_to(to, from)?),
+            None => None,
+        };
+        let frames = matches.value_of("record-frames").unwrap_or(DEFAULT_RECORD_FRAMES);
+        let scale = matches.value_of("record-scale").unwrap_or(DEFAULT_RECORD_SCALE);
+
+        config = config.with_record(
+            String::from(record),
+            from,
+            to,
+            validate_record_frames(frames)?,
+            ImageStyle::new().with_scale(validate_record_scale(scale)?),
+            matches.is_present("record-loop"));
+    }
+
+    Ok(config)
 }
 
-fn validate_config(width: &str, height: &str, sleep: &str, ratio: &str) -> Result<Config, String> {
+fn validate_config(width: &str, height: &str, sleep: &str, ratio: &str) -> Result<Config, Error> {
     let width = match width.parse::<usize>() {
         Ok(w) => {
             if w < 1 {
-                return Err(error_bad_option_to_small("width", 1))
+                return Err(error_bad_option_to_small("width", Some(width), 1))
             }
 
             w
         },
-        Err(_) => return Err(error_bad_option_not_number("width")),
+        Err(_) => return Err(error_bad_option_not_number("width", Some(width))),
     };
 
     let height = match height.parse::<usize>() {
         Ok(h) => {
             if h < 1 {
-                return Err(error_bad_option_to_small("height", 1))
+                return Err(error_bad_option_to_small("height", Some(height), 1))
             }
 
             h
         },
-        Err(_) => return Err(error_bad_option_not_number("height")),
+        Err(_) => return Err(error_bad_option_not_number("height", Some(height))),
     };
 
-    let sleep = match sleep.parse::<u64>() {
-        Ok(s) => {
+    let sleep = match parse_milliseconds(sleep) {
+        Some(s) => {
             if s < 1 {
-                return Err(error_bad_option_to_small("sleep", 1))
+                return Err(error_bad_option_to_small("sleep", Some(sleep), 1))
             }
 
             s
         },
-        Err(_) => return Err(error_bad_option_not_number("sleep")),
+        None => return Err(error_bad_option_not_number("sleep", Some(sleep))),
     };
 
     let ratio = match ratio.parse::<u32>() {
         Ok(r) => {
             if r < 1 {
-                return Err(error_bad_option_to_small("ratio", 1))
+                return Err(error_bad_option_to_small("ratio", Some(ratio), 1))
             }
 
             r
         },
-        Err(_) => return Err(error_bad_option_not_number("ratio")),
+        Err(_) => return Err(error_bad_option_not_number("ratio", Some(ratio))),
     };
 
     Ok(Config::new(width, height, sleep, ratio))
 }
 
-static OPTION_ERROR_PREFIX: &'static str = "Bad option:";
+/// Parses a duration in milliseconds from a number with optional unit: `ms` for milliseconds or
+/// `s` for seconds. Numbers without unit are seconds.
+fn parse_milliseconds(duration: &str) -> Option<u64> {
+    if let Some(milliseconds) = duration.strip_suffix("ms") {
+        milliseconds.parse::<u64>().ok()
+    } else {
+        let seconds = duration.strip_suffix('s').unwrap_or(duration);
+        seconds.parse::<u64>().ok().and_then(|s| s.checked_mul(1000))
+    }
+}
+
+fn validate_fps(fps: &str) -> Result<u64, Error> {
+    match fps.parse::<u64>() {
+        Ok(f) if f < 1 => Err(error_bad_option_to_small("fps", Some(fps), 1)),
+        Ok(f) if f > MAX_FPS => Err(error_bad_option_to_large("fps", Some(fps), MAX_FPS as usize)),
+        Ok(f) => Ok(f),
+        Err(_) => Err(error_bad_option_not_number("fps", Some(fps))),
+    }
+}
+
+fn validate_rule(rule: &str) -> Result<Rule, Error> {
+    Rule::parse(rule).map_err(|reason| error_bad_option_invalid("rule", Some(rule), &reason))
+}
+
+fn validate_topology(topology: &str) -> Result<Topology, Error> {
+    Topology::parse(topology).map_err(|reason| error_bad_option_invalid("topology", Some(topology), &reason))
+}
+
+fn validate_render(render: &str) -> Result<Render, Error> {
+    Render::parse(render).map_err(|reason| error_bad_option_invalid("render", Some(render), &reason))
+}
+
+fn validate_color(color: &str) -> Result<ColorMode, Error> {
+    ColorMode::parse(color).map_err(|reason| error_bad_option_invalid("color", Some(color), &reason))
+}
+
+fn validate_trail(trail: &str) -> Result<usize, Error> {
+    trail.parse::<usize>().map_err(|_| error_bad_option_not_number("trail", Some(trail)))
+}
+
+fn validate_theme(theme: &str) -> Result<Theme, Error> {
+    Theme::parse(theme).map_err(|reason| error_bad_option_invalid("theme", Some(theme), &reason))
+}
+
+fn validate_glyphs(glyphs: &str) -> Result<Theme, Error> {
+    Theme::parse_glyphs(glyphs).map_err(|reason| error_bad_option_invalid("glyphs", Some(glyphs), &reason))
+}
+
+fn validate_snapshot_every(every: &str) -> Result<usize, Error> {
+    match every.parse::<usize>() {
+        Ok(e) if e < 1 => Err(error_bad_option_to_small("snapshot-every", Some(every), 1)),
+        Ok(e) => Ok(e),
+        Err(_) => Err(error_bad_option_not_number("snapshot-every", Some(every))),
+    }
+}
+
+fn validate_snapshot_format(format: &str) -> Result<ImageFormat, Error> {
+    ImageFormat::parse(format).map_err(|reason| error_bad_option_invalid("snapshot-format", Some(format), &reason))
+}
+
+fn validate_snapshot_scale(scale: &str) -> Result<usize, Error> {
+    match scale.parse::<usize>() {
+        Ok(s) if s < 1 => Err(error_bad_option_to_small("snapshot-scale", Some(scale), 1)),
+        Ok(s) => Ok(s),
+        Err(_) => Err(error_bad_option_not_number("snapshot-scale", Some(scale))),
+    }
+}
+
+fn validate_record_to(to: &str, from: usize) -> Result<usize, Error> {
+    match validate_generation("record-to", to)? {
+        t if t < from => Err(error_bad_option_to_small("record-to", Some(to), from)),
+        t => Ok(t),
+    }
+}
+
+fn validate_record_frames(frames: &str) -> Result<usize, Error> {
+    match frames.parse::<usize>() {
+        Ok(f) if f < 1 => Err(error_bad_option_to_small("record-frames", Some(frames), 1)),
+        Ok(f) => Ok(f),
+        Err(_) => Err(error_bad_option_not_number("record-frames", Some(frames))),
+    }
+}
+
+fn validate_record_scale(scale: &str) -> Result<usize, Error> {
+    match scale.parse::<usize>() {
+        Ok(s) if s < 1 => Err(error_bad_option_to_small("record-scale", Some(scale), 1)),
+        Ok(s) => Ok(s),
+        Err(_) => Err(error_bad_option_not_number("record-scale", Some(scale))),
+    }
+}
 
-fn error_bad_option_not_number(name: &str) -> String {
-    format!("{} Not negative number expected as option '--{}'!", OPTION_ERROR_PREFIX, name)
+fn validate_history(history: &str) -> Result<usize, Error> {
+    match history.parse::<usize>() {
+        Ok(h) if h < 1 => Err(error_bad_option_to_small("history", Some(history), 1)),
+        Ok(h) => Ok(h),
+        Err(_) => Err(error_bad_option_not_number("history", Some(history))),
+    }
 }
 
-fn error_bad_option_to_small(name: &str, min: usize) -> String {
-    format!("{} Too small value for option '--{}' given! Minimum is {}.", OPTION_ERROR_PREFIX, name, min)
+fn validate_zoom(zoom: &str) -> Result<usize, Error> {
+    match zoom.parse::<usize>() {
+        Ok(z) if z < 1 => Err(error_bad_option_to_small("zoom", Some(zoom), 1)),
+        Ok(z) => Ok(z),
+        Err(_) => Err(error_bad_option_not_number("zoom", Some(zoom))),
+    }
+}
+
+fn validate_seed(seed: &str) -> Result<u64, Error> {
+    seed.parse::<u64>().map_err(|_| error_bad_option_not_number("seed", Some(seed)))
+}
+
+fn validate_generation(name: &str, generation: &str) -> Result<usize, Error> {
+    generation.parse::<usize>().map_err(|_| error_bad_option_not_number(name, Some(generation)))
+}
+
+fn error_bad_option_not_number(name: &str, value: Option<&str>) -> Error {
+    Error::option(name, value, Reason::NotNumber)
+}
+
+fn error_bad_option_to_large(name: &str, value: Option<&str>, max: usize) -> Error {
+    Error::option(name, value, Reason::AboveMaximum(max))
+}
+
+fn error_bad_option_conflict(name: &str, value: Option<&str>, other: &str) -> Error {
+    Error::option(name, value, Reason::Conflict(String::from(other)))
+}
+
+fn error_bad_option_invalid(name: &str, value: Option<&str>, reason: &str) -> Error {
+    Error::option(name, value, Reason::Invalid(String::from(reason)))
+}
+
+fn error_bad_option_to_small(name: &str, value: Option<&str>, min: usize) -> Error {
+    Error::option(name, value, Reason::BelowMinimum(min))
 }
 
 /// This struct holds the configuration for the game.
@@ -86,13 +386,199 @@ fn error_bad_option_to_small(name: &str, min: usize) -> String {
 pub struct Config {
     width: usize,
     height: usize,
+    /// Milliseconds per iteration.
     sleep: u64,
     ratio: u32,
+    seed: u64,
+    rule: Option<Rule>,
+    topology: Topology,
+    history: usize,
+    generations: Option<usize>,
+    headless: bool,
+    interactive: bool,
+    edit: bool,
+    flicker_free: bool,
+    zoom: usize,
+    render: Render,
+    color: ColorMode,
+    trail: usize,
+    theme: Theme,
+    follow: bool,
+    pattern: Option<String>,
+    dump: Option<String>,
+    dump_generation: Option<usize>,
+    snapshot_every: Option<usize>,
+    snapshot_dir: String,
+    snapshot_format: ImageFormat,
+    snapshot_style: ImageStyle,
+    record: Option<String>,
+    record_from: usize,
+    record_to: Option<usize>,
+    record_frames: usize,
+    record_style: ImageStyle,
+    record_loop: bool,
 }
 
 impl Config {
     fn new(width: usize, height: usize, sleep: u64, ratio: u32) -> Config {
-        Config { width, height, sleep, ratio }
+        Config {
+            width,
+            height,
+            sleep,
+            ratio,
+            seed: 0,
+            rule: None,
+            topology: Topology::Plane,
+            history: 64,
+            generations: None,
+            headless: false,
+            interactive: false,
+            edit: false,
+            flicker_free: false,
+            zoom: 1,
+            render: Render::Cells,
+            color: ColorMode::Off,
+            trail: 1,
+            theme: Theme::sun(),
+            follow: false,
+            pattern: None,
+            dump: None,
+            dump_generation: None,
+            snapshot_every: None,
+            snapshot_dir: String::from(DEFAULT_SNAPSHOT_DIR),
+            snapshot_format: ImageFormat::Png,
+            snapshot_style: ImageStyle::new(),
+            record: None,
+            record_from: 0,
+            record_to: None,
+            record_frames: 500,
+            record_style: ImageStyle::new(),
+            record_loop: false,
+        }
+    }
+
+    fn with_fps(self, fps: u64) -> Config {
+        Config { sleep: 1000 / fps, ..self }
+    }
+
+    pub(crate) fn with_seed(self, seed: u64) -> Config {
+        Config { seed, ..self }
+    }
+
+    fn with_rule(self, rule: Rule) -> Config {
+        Config { rule: Some(rule), ..self }
+    }
+
+    fn with_topology(self, topology: Topology) -> Config {
+        Config { topology, ..self }
+    }
+
+    fn with_history(self, history: usize) -> Config {
+        Config { history, ..self }
+    }
+
+    fn with_generations(self, generations: usize) -> Config {
+        Config { generations: Some(generations), ..self }
+    }
+
+    fn with_headless(self) -> Config {
+        Config { headless: true, ..self }
+    }
+
+    fn with_interactive(self) -> Config {
+        Config { interactive: true, ..self }
+    }
+
+    fn with_edit(self) -> Config {
+        Config { edit: true, ..self }
+    }
+
+    fn with_flicker_free(self) -> Config {
+        Config { flicker_free: true, ..self }
+    }
+
+    fn with_zoom(self, zoom: usize) -> Config {
+        Config { zoom, ..self }
+    }
+
+    fn with_render(self, render: Render) -> Config {
+        Config { render, ..self }
+    }
+
+    fn with_color(self, color: ColorMode) -> Config {
+        Config { color, ..self }
+    }
+
+    fn with_trail(self, trail: usize) -> Config {
+        Config { trail, ..self }
+    }
+
+    fn with_theme(self, theme: Theme) -> Config {
+        Config { theme, ..self }
+    }
+
+    fn with_follow(self) -> Config {
+        Config { follow: true, ..self }
+    }
+
+    fn with_pattern(self, pattern: String) -> Config {
+        Config { pattern: Some(pattern), ..self }
+    }
+
+    fn with_dump(self, dump: String, dump_generation: Option<usize>) -> Config {
+        Config { dump: Some(dump), dump_generation, ..self }
+    }
+
+    fn with_snapshots(self, every: usize, dir: String, format: ImageFormat, style: ImageStyle) -> Config {
+        Config {
+            snapshot_every: Some(every),
+            snapshot_dir: dir,
+            snapshot_format: format,
+            snapshot_style: style,
+            ..self
+        }
+    }
+
+    fn with_record(
+        self,
+        record: String,
+        from: usize,
+        to: Option<usize>,
+        frames: usize,
+        style: ImageStyle,
+        repeat: bool) -> Config {
+        Config {
+            record: Some(record),
+            record_from: from,
+            record_to: to,
+            record_frames: frames,
+            record_style: style,
+            record_loop: repeat,
+            ..self
+        }
+    }
+
+    /// Creates a copy of this config with half the duration of an iteration, but at least 1ms.
+    pub(crate) fn faster(self) -> Config {
+        Config { sleep: (self.sleep / 2).max(1), ..self }
+    }
+
+    /// Creates a copy of this config with twice the duration of an iteration.
+    pub(crate) fn slower(self) -> Config {
+        Config { sleep: self.sleep.saturating_mul(2), ..self }
+    }
+
+    /// Creates a copy of this config adapted to the given pattern.
+    ///
+    /// The space is enlarged if the pattern does not fit into it, and the pattern's rule is used
+    /// if no rule was configured explicitly.
+    pub(crate) fn adapt_to_pattern(self, pattern: &Pattern) -> Config {
+        Config {
+            width: self.width.max(pattern.get_width()),
+            height: self.height.max(pattern.get_height()),
+            rule: self.rule.or_else(|| pattern.get_rule().cloned()),
+            ..self
+        }
     }
 
     /// Get the width of the space the population have.
@@ -105,20 +591,179 @@ impl Config {
         self.height
     }
 
-    /// Seconds to sleep between the population's iterations.
+    /// Duration of one population's iteration.
+    ///
+    /// The time needed to calculate and print an iteration is part of this duration, so the
+    /// remaining time is slept.
     pub fn get_sleep(&self) -> Duration {
-        time::Duration::from_secs(self.sleep)
+        time::Duration::from_millis(self.sleep)
     }
 
     /// The probability ration for generating initial population.
     pub fn get_ratio(&self) -> u32 {
         self.ratio
     }
+
+    /// The seed of the random number generator used for generating the initial population.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The rule which decides about birth and survival of cells.
+    ///
+    /// Conway's `B3/S23` if no rule was configured.
+    pub fn get_rule(&self) -> Rule {
+        self.rule.clone().unwrap_or_else(Rule::conway)
+    }
+
+    /// How the edges of the space the population have are connected.
+    pub fn get_topology(&self) -> &Topology {
+        &self.topology
+    }
+
+    /// How many generations are remembered to detect still lifes and oscillators.
+    pub fn get_history(&self) -> usize {
+        self.history
+    }
+
+    /// The optional maximum number of generations to run.
+    pub fn get_generations(&self) -> Option<usize> {
+        self.generations
+    }
+
+    /// Whether the game runs without printing generations and without sleeping.
+    pub fn is_headless(&self) -> bool {
+        self.headless
+    }
+
+    /// Whether the game is controlled with the keyboard while it runs.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Whether the initial population is drawn by hand before the game starts.
+    pub fn is_edit(&self) -> bool {
+        self.edit
+    }
+
+    /// Whether the frames are drawn onto the alternate screen by only rewriting changed cells.
+    pub fn is_flicker_free(&self) -> bool {
+        self.flicker_free
+    }
+
+    /// Number of places per character in each direction shown initially.
+    pub fn get_zoom(&self) -> usize {
+        self.zoom
+    }
+
+    /// How many places are packed into one character on the screen.
+    pub fn get_render(&self) -> &Render {
+        &self.render
+    }
+
+    /// How living cells are coloured by their age on the screen.
+    pub fn get_color(&self) -> &ColorMode {
+        &self.color
+    }
+
+    /// Number of generations dead cells are shown as fading trail. Zero turns trails off.
+    pub fn get_trail(&self) -> usize {
+        self.trail
+    }
+
+    /// The glyphs cells and places are drawn with, and whether a frame is drawn around them.
+    pub fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Whether the shown window initially follows the living cells.
+    pub fn is_follow(&self) -> bool {
+        self.follow
+    }
+
+    /// The file a drawing is saved to: the dump file if given, else the default one.
+    pub fn get_drawing_file(&self) -> &str {
+        self.dump.as_deref().unwrap_or(DEFAULT_DRAWING)
+    }
+
+    /// The optional file to load the initial population from.
+    pub fn get_pattern(&self) -> Option<&String> {
+        self.pattern.as_ref()
+    }
+
+    /// The optional file to save a generation of the population to.
+    pub fn get_dump(&self) -> Option<&String> {
+        self.dump.as_ref()
+    }
+
+    /// The generation to save to the dump file. If none is given the final one is saved.
+    pub fn get_dump_generation(&self) -> Option<usize> {
+        self.dump_generation
+    }
+
+    /// The optional number of generations after which a snapshot image is saved.
+    pub fn get_snapshot_every(&self) -> Option<usize> {
+        self.snapshot_every
+    }
+
+    /// The directory snapshot images are saved to.
+    pub fn get_snapshot_dir(&self) -> &str {
+        &self.snapshot_dir
+    }
+
+    /// The image format of snapshots.
+    pub fn get_snapshot_format(&self) -> &ImageFormat {
+        &self.snapshot_format
+    }
+
+    /// How populations are drawn into snapshot images.
+    pub fn get_snapshot_style(&self) -> &ImageStyle {
+        &self.snapshot_style
+    }
+
+    /// The optional file the generations are recorded to as animated GIF.
+    pub fn get_record(&self) -> Option<&String> {
+        self.record.as_ref()
+    }
+
+    /// The first recorded generation.
+    pub fn get_record_from(&self) -> usize {
+        self.record_from
+    }
+
+    /// The optional last recorded generation.
+    pub fn get_record_to(&self) -> Option<usize> {
+        self.record_to
+    }
+
+    /// The maximum number of recorded frames.
+    pub fn get_record_frames(&self) -> usize {
+        self.record_frames
+    }
+
+    /// How populations are drawn into the frames of the recording.
+    pub fn get_record_style(&self) -> &ImageStyle {
+        &self.record_style
+    }
+
+    /// Whether the recorded animation starts over after the last frame.
+    pub fn is_record_loop(&self) -> bool {
+        self.record_loop
+    }
 }
 
 impl fmt::Display for Config {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Width:     {:5}, Height: {:5}, Sleep: {:5}, Ratio: {:5}", self.width, self.height, self.sleep, self.ratio)
+        write!(
+            f,
+            "Width:     {:5}, Height: {:5}, Sleep: {:5}ms, Ratio: {:5}, Rule: {}, Topology: {}, Seed: {}",
+            self.width,
+            self.height,
+            self.sleep,
+            self.ratio,
+            self.get_rule(),
+            self.topology,
+            self.seed)
     }
 }
 
@@ -133,7 +778,7 @@ mod tests {
 
         assert_that!(
             format!("{}", sut),
-            is(equal_to(String::from("Width:        42, Height:    23, Sleep:     5, Ratio:     3"))));
+            is(equal_to(String::from("Width:        42, Height:    23, Sleep:     5ms, Ratio:     3, Rule: B3/S23, Topology: plane, Seed: 0"))));
     }
 
     #[test]
@@ -142,8 +787,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Not negative number expected as option '--width'!")))));
+            is(equal_to(Err(Error::option("width", Some("foo"), Reason::NotNumber)))));
     }
 
     #[test]
@@ -152,8 +796,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Too small value for option '--width' given! Minimum is 1.")))));
+            is(equal_to(Err(Error::option("width", Some("0"), Reason::BelowMinimum(1))))));
     }
 
     #[test]
@@ -162,8 +805,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Not negative number expected as option '--height'!")))));
+            is(equal_to(Err(Error::option("height", Some("foo"), Reason::NotNumber)))));
     }
 
     #[test]
@@ -172,8 +814,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Too small value for option '--height' given! Minimum is 1.")))));
+            is(equal_to(Err(Error::option("height", Some("0"), Reason::BelowMinimum(1))))));
     }
 
     #[test]
@@ -182,8 +823,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Not negative number expected as option '--sleep'!")))));
+            is(equal_to(Err(Error::option("sleep", Some("foo"), Reason::NotNumber)))));
     }
 
     #[test]
@@ -192,8 +832,39 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Too small value for option '--sleep' given! Minimum is 1.")))));
+            is(equal_to(Err(Error::option("sleep", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_config_sleep_in_milliseconds() {
+        let result = validate_config("1", "1", "250ms", "1").unwrap();
+
+        assert_that!(result.get_sleep(), is(equal_to(time::Duration::from_millis(250))));
+    }
+
+    #[test]
+    fn validate_config_sleep_in_seconds() {
+        let result = validate_config("1", "1", "2s", "1").unwrap();
+
+        assert_that!(result.get_sleep(), is(equal_to(time::Duration::from_secs(2))));
+    }
+
+    #[test]
+    fn validate_config_sleep_in_milliseconds_is_to_small() {
+        let result = validate_config("1", "1", "0ms", "1");
+
+        assert_that!(
+            result,
+            is(equal_to(Err(Error::option("sleep", Some("0ms"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_config_sleep_with_unknown_unit() {
+        let result = validate_config("1", "1", "5m", "1");
+
+        assert_that!(
+            result,
+            is(equal_to(Err(Error::option("sleep", Some("5m"), Reason::NotNumber)))));
     }
 
     #[test]
@@ -202,8 +873,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Not negative number expected as option '--ratio'!")))));
+            is(equal_to(Err(Error::option("ratio", Some("foo"), Reason::NotNumber)))));
     }
 
     #[test]
@@ -212,8 +882,7 @@ mod tests {
 
         assert_that!(
             result,
-            is(equal_to(Err(
-                String::from("Bad option: Too small value for option '--ratio' given! Minimum is 1.")))));
+            is(equal_to(Err(Error::option("ratio", Some("0"), Reason::BelowMinimum(1))))));
     }
 
     #[test]
@@ -227,5 +896,265 @@ mod tests {
         assert_that!(result.get_height(), is(equal_to(2)));
         assert_that!(result.get_sleep(), is(equal_to(time::Duration::from_secs(3))));
         assert_that!(result.get_ratio(), is(equal_to(4)));
+        assert_that!(result.get_rule(), is(equal_to(Rule::conway())));
+        assert_that!(result.get_pattern(), is(equal_to(None)));
+        assert_that!(result.get_topology(), is(equal_to(&Topology::Plane)));
+    }
+
+    #[test]
+    fn fmt_with_rule() {
+        let sut = Config::new(42, 23, 5, 3).with_rule(Rule::parse("B36/S23").unwrap());
+
+        assert_that!(
+            format!("{}", sut),
+            is(equal_to(String::from("Width:        42, Height:    23, Sleep:     5ms, Ratio:     3, Rule: B36/S23, Topology: plane, Seed: 0"))));
+    }
+
+    #[test]
+    fn adapt_to_pattern_enlarges_space_and_takes_rule() {
+        let pattern = Pattern::new(50, 10, Vec::new()).unwrap().with_rule(Rule::parse("B36/S23").unwrap());
+        let sut = Config::new(42, 23, 5, 3).adapt_to_pattern(&pattern);
+
+        assert_that!(sut.get_width(), is(equal_to(50)));
+        assert_that!(sut.get_height(), is(equal_to(23)));
+        assert_that!(sut.get_rule(), is(equal_to(Rule::parse("B36/S23").unwrap())));
+    }
+
+    #[test]
+    fn adapt_to_pattern_keeps_explicit_rule() {
+        let pattern = Pattern::new(5, 5, Vec::new()).unwrap().with_rule(Rule::parse("B36/S23").unwrap());
+        let sut = Config::new(42, 23, 5, 3)
+            .with_rule(Rule::parse("B2/S").unwrap())
+            .adapt_to_pattern(&pattern);
+
+        assert_that!(sut.get_rule(), is(equal_to(Rule::parse("B2/S").unwrap())));
+    }
+
+    #[test]
+    fn fmt_with_seed() {
+        let sut = Config::new(42, 23, 5, 3).with_seed(1234567890);
+
+        assert_that!(
+            format!("{}", sut),
+            is(equal_to(String::from(
+                "Width:        42, Height:    23, Sleep:     5ms, Ratio:     3, Rule: B3/S23, Topology: plane, Seed: 1234567890"))));
+    }
+
+    #[test]
+    fn validate_fps_is_not_number() {
+        assert_that!(
+            validate_fps("fast"),
+            is(equal_to(Err(Error::option("fps", Some("fast"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_fps_is_to_small() {
+        assert_that!(
+            validate_fps("0"),
+            is(equal_to(Err(Error::option("fps", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_fps_is_to_large() {
+        assert_that!(
+            validate_fps("1001"),
+            is(equal_to(Err(Error::option("fps", Some("1001"), Reason::AboveMaximum(1000))))));
+    }
+
+    #[test]
+    fn with_fps_sets_sleep() {
+        let sut = Config::new(1, 1, 1000, 1).with_fps(validate_fps("25").unwrap());
+
+        assert_that!(sut.get_sleep(), is(equal_to(time::Duration::from_millis(40))));
+    }
+
+    #[test]
+    fn validate_history_is_not_usize() {
+        assert_that!(
+            validate_history("foo"),
+            is(equal_to(Err(Error::option("history", Some("foo"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_history_is_to_small() {
+        assert_that!(
+            validate_history("0"),
+            is(equal_to(Err(Error::option("history", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_history_with_sane_value() {
+        assert_that!(validate_history("100"), is(equal_to(Ok(100))));
+    }
+
+    #[test]
+    fn validate_render_is_invalid() {
+        assert_that!(
+            validate_render("ascii"),
+            is(equal_to(Err(Error::option("render", Some("ascii"), Reason::Invalid(String::from("Unknown render mode 'ascii'! Expected 'cells', 'half-blocks' or 'braille'.")))))));
+    }
+
+    #[test]
+    fn validate_render_with_sane_value() {
+        assert_that!(validate_render("braille"), is(equal_to(Ok(Render::Braille))));
+    }
+
+    #[test]
+    fn validate_color_is_invalid() {
+        assert_that!(
+            validate_color("16"),
+            is(equal_to(Err(Error::option("color", Some("16"), Reason::Invalid(String::from("Unknown color mode '16'! Expected 'off', '256' or 'truecolor'.")))))));
+    }
+
+    #[test]
+    fn validate_trail_is_not_usize() {
+        assert_that!(
+            validate_trail("-1"),
+            is(equal_to(Err(Error::option("trail", Some("-1"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_trail_may_be_zero() {
+        assert_that!(validate_trail("0"), is(equal_to(Ok(0))));
+    }
+
+    #[test]
+    fn validate_theme_is_invalid() {
+        assert_that!(
+            validate_theme("fancy"),
+            is(equal_to(Err(Error::option("theme", Some("fancy"), Reason::Invalid(String::from("Unknown theme 'fancy'! Expected 'sun', 'ascii', 'block' or 'emoji'.")))))));
+    }
+
+    #[test]
+    fn validate_glyphs_with_sane_value() {
+        assert_that!(validate_glyphs("#,.,-").is_ok(), is(true));
+    }
+
+    #[test]
+    fn validate_snapshot_every_is_to_small() {
+        assert_that!(
+            validate_snapshot_every("0"),
+            is(equal_to(Err(Error::option("snapshot-every", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_snapshot_format_is_invalid() {
+        assert_that!(
+            validate_snapshot_format("gif"),
+            is(equal_to(Err(Error::option("snapshot-format", Some("gif"), Reason::Invalid(String::from("Unknown image format 'gif'! Expected 'pbm', 'pgm' or 'png'.")))))));
+    }
+
+    #[test]
+    fn validate_snapshot_scale_is_not_usize() {
+        assert_that!(
+            validate_snapshot_scale("big"),
+            is(equal_to(Err(Error::option("snapshot-scale", Some("big"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_record_to_is_before_record_from() {
+        assert_that!(
+            validate_record_to("4", 5),
+            is(equal_to(Err(Error::option("record-to", Some("4"), Reason::BelowMinimum(5))))));
+    }
+
+    #[test]
+    fn validate_record_to_with_sane_value() {
+        assert_that!(validate_record_to("5", 5), is(equal_to(Ok(5))));
+    }
+
+    #[test]
+    fn validate_record_frames_is_to_small() {
+        assert_that!(
+            validate_record_frames("0"),
+            is(equal_to(Err(Error::option("record-frames", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_zoom_is_to_small() {
+        assert_that!(
+            validate_zoom("0"),
+            is(equal_to(Err(Error::option("zoom", Some("0"), Reason::BelowMinimum(1))))));
+    }
+
+    #[test]
+    fn validate_zoom_with_sane_value() {
+        assert_that!(validate_zoom("4"), is(equal_to(Ok(4))));
+    }
+
+    #[test]
+    fn validate_seed_is_not_u64() {
+        assert_that!(
+            validate_seed("foo"),
+            is(equal_to(Err(Error::option("seed", Some("foo"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_seed_with_sane_value() {
+        assert_that!(validate_seed("18446744073709551615"), is(equal_to(Ok(u64::MAX))));
+    }
+
+    #[test]
+    fn validate_generation_is_not_usize() {
+        assert_that!(
+            validate_generation("dump-generation", "-1"),
+            is(equal_to(Err(Error::option("dump-generation", Some("-1"), Reason::NotNumber)))));
+    }
+
+    #[test]
+    fn validate_generation_with_sane_value() {
+        assert_that!(validate_generation("dump-generation", "42"), is(equal_to(Ok(42))));
+    }
+
+    #[test]
+    fn validate_rule_is_invalid() {
+        assert_that!(
+            validate_rule("B3/S239"),
+            is(equal_to(Err(Error::option("rule", Some("B3/S239"), Reason::Invalid(String::from("Rule contains invalid neighbour count '9'!")))))));
+    }
+
+    #[test]
+    fn fmt_with_topology() {
+        let sut = Config::new(42, 23, 5, 3).with_topology(Topology::Torus);
+
+        assert_that!(
+            format!("{}", sut),
+            is(equal_to(String::from("Width:        42, Height:    23, Sleep:     5ms, Ratio:     3, Rule: B3/S23, Topology: torus, Seed: 0"))));
+    }
+
+    #[test]
+    fn validate_topology_is_invalid() {
+        assert_that!(
+            validate_topology("sphere"),
+            is(equal_to(Err(Error::option("topology", Some("sphere"), Reason::Invalid(String::from("Unknown topology 'sphere'! Expected 'plane' or 'torus'.")))))));
+    }
+
+    #[test]
+    fn validate_topology_with_sane_value() {
+        assert_that!(validate_topology("torus"), is(equal_to(Ok(Topology::Torus))));
+    }
+
+    #[test]
+    fn get_drawing_file_prefers_dump() {
+        let sut = Config::new(1, 1, 1, 1);
+
+        assert_that!(sut.get_drawing_file(), is(equal_to(DEFAULT_DRAWING)));
+        assert_that!(sut.with_dump(String::from("glider.cells"), None).get_drawing_file(), is(equal_to("glider.cells")));
+    }
+
+    #[test]
+    fn faster_halves_sleep() {
+        assert_that!(Config::new(1, 1, 100, 1).faster().get_sleep(), is(equal_to(time::Duration::from_millis(50))));
+        assert_that!(Config::new(1, 1, 1, 1).faster().get_sleep(), is(equal_to(time::Duration::from_millis(1))));
+    }
+
+    #[test]
+    fn slower_doubles_sleep() {
+        assert_that!(Config::new(1, 1, 100, 1).slower().get_sleep(), is(equal_to(time::Duration::from_millis(200))));
+    }
+
+    #[test]
+    fn validate_rule_with_sane_value() {
+        assert_that!(validate_rule("B36/S23"), is(equal_to(Ok(Rule::parse("B36/S23").unwrap()))));
     }
 }
@@ -0,0 +1,141 @@
+use termion::event::Key;
+
+/// The commands a user can give with the keyboard while the game runs interactively.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Command {
+    /// Pauses a running game or resumes a paused one.
+    TogglePause,
+    /// Advances exactly one generation.
+    Step,
+    /// Halves the duration of an iteration.
+    Faster,
+    /// Doubles the duration of an iteration.
+    Slower,
+    /// Starts over with the loaded pattern or drawing, else with a new random population.
+    Reseed,
+    /// Moves the viewport by the given number of columns and rows.
+    Pan(isize, isize),
+    /// Shows fewer places per character.
+    ZoomIn,
+    /// Shows more places per character.
+    ZoomOut,
+    /// Starts or stops following the living cells with the viewport.
+    ToggleFollow,
+    /// Ends the game.
+    Quit,
+}
+
+/// Short description of the keys, shown below the header.
+pub static KEY_HELP: &str =
+    "[space] pause/resume  [n] next generation  [+/-] speed  [arrows] pan  [i/o] zoom  [c] follow  [r] restart  [q] quit";
+
+impl Command {
+    /// Maps a pressed key to its command, if any.
+    pub fn from_key(key: &Key) -> Option<Command> {
+        match *key {
+            Key::Char(' ') => Some(Command::TogglePause),
+            Key::Char('n') => Some(Command::Step),
+            Key::Char('+') => Some(Command::Faster),
+            Key::Char('-') => Some(Command::Slower),
+            Key::Char('r') => Some(Command::Reseed),
+            Key::Up => Some(Command::Pan(0, -1)),
+            Key::Down => Some(Command::Pan(0, 1)),
+            Key::Left => Some(Command::Pan(-1, 0)),
+            Key::Right => Some(Command::Pan(1, 0)),
+            Key::Char('i') => Some(Command::ZoomIn),
+            Key::Char('o') => Some(Command::ZoomOut),
+            Key::Char('c') => Some(Command::ToggleFollow),
+            Key::Char('q') | Key::Ctrl('c') => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// The commands a user can give with the keyboard while drawing a population.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EditCommand {
+    /// Moves the cursor one row up.
+    Up,
+    /// Moves the cursor one row down.
+    Down,
+    /// Moves the cursor one column to the left.
+    Left,
+    /// Moves the cursor one column to the right.
+    Right,
+    /// Toggles the cell at the cursor.
+    Toggle,
+    /// Removes all cells.
+    Clear,
+    /// Puts a living cell at every place.
+    Fill,
+    /// Swaps living cells and empty places.
+    Invert,
+    /// Saves the drawing to a pattern file.
+    Save,
+    /// Starts the simulation with the drawing.
+    Start,
+    /// Ends the game without simulation.
+    Quit,
+}
+
+/// Short description of the keys of the editor, shown below the header.
+pub static EDIT_KEY_HELP: &str =
+    "[arrows/hjkl] move  [space] toggle  [c] clear  [f] fill  [i] invert  [s] save  [enter] start  [q] quit";
+
+impl EditCommand {
+    /// Maps a pressed key to its command, if any.
+    pub fn from_key(key: &Key) -> Option<EditCommand> {
+        match *key {
+            Key::Up | Key::Char('k') => Some(EditCommand::Up),
+            Key::Down | Key::Char('j') => Some(EditCommand::Down),
+            Key::Left | Key::Char('h') => Some(EditCommand::Left),
+            Key::Right | Key::Char('l') => Some(EditCommand::Right),
+            Key::Char(' ') => Some(EditCommand::Toggle),
+            Key::Char('c') => Some(EditCommand::Clear),
+            Key::Char('f') => Some(EditCommand::Fill),
+            Key::Char('i') => Some(EditCommand::Invert),
+            Key::Char('s') => Some(EditCommand::Save),
+            Key::Char('\n') => Some(EditCommand::Start),
+            Key::Char('q') | Key::Ctrl('c') => Some(EditCommand::Quit),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn from_key_with_command() {
+        assert_that!(Command::from_key(&Key::Char(' ')), is(equal_to(Some(Command::TogglePause))));
+        assert_that!(Command::from_key(&Key::Char('n')), is(equal_to(Some(Command::Step))));
+        assert_that!(Command::from_key(&Key::Char('+')), is(equal_to(Some(Command::Faster))));
+        assert_that!(Command::from_key(&Key::Char('-')), is(equal_to(Some(Command::Slower))));
+        assert_that!(Command::from_key(&Key::Char('r')), is(equal_to(Some(Command::Reseed))));
+        assert_that!(Command::from_key(&Key::Up), is(equal_to(Some(Command::Pan(0, -1)))));
+        assert_that!(Command::from_key(&Key::Right), is(equal_to(Some(Command::Pan(1, 0)))));
+        assert_that!(Command::from_key(&Key::Char('i')), is(equal_to(Some(Command::ZoomIn))));
+        assert_that!(Command::from_key(&Key::Char('o')), is(equal_to(Some(Command::ZoomOut))));
+        assert_that!(Command::from_key(&Key::Char('c')), is(equal_to(Some(Command::ToggleFollow))));
+        assert_that!(Command::from_key(&Key::Char('q')), is(equal_to(Some(Command::Quit))));
+        assert_that!(Command::from_key(&Key::Ctrl('c')), is(equal_to(Some(Command::Quit))));
+    }
+
+    #[test]
+    fn from_key_without_command() {
+        assert_that!(Command::from_key(&Key::Char('x')), is(equal_to(None)));
+        assert_that!(Command::from_key(&Key::PageUp), is(equal_to(None)));
+    }
+
+    #[test]
+    fn edit_command_from_key() {
+        assert_that!(EditCommand::from_key(&Key::Up), is(equal_to(Some(EditCommand::Up))));
+        assert_that!(EditCommand::from_key(&Key::Char('j')), is(equal_to(Some(EditCommand::Down))));
+        assert_that!(EditCommand::from_key(&Key::Char(' ')), is(equal_to(Some(EditCommand::Toggle))));
+        assert_that!(EditCommand::from_key(&Key::Char('\n')), is(equal_to(Some(EditCommand::Start))));
+        assert_that!(EditCommand::from_key(&Key::Ctrl('c')), is(equal_to(Some(EditCommand::Quit))));
+        assert_that!(EditCommand::from_key(&Key::Char('x')), is(equal_to(None)));
+    }
+}
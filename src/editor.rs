@@ -0,0 +1,183 @@
+use std::fmt;
+use crate::control::EditCommand;
+use crate::place::Place;
+use crate::population::Population;
+
+/// Starts and ends reverse video, used to highlight the cursor.
+static CURSOR_START: &str = "\x1b[7m";
+static CURSOR_END: &str = "\x1b[0m";
+
+/// This struct holds a population which is drawn by hand with a movable cursor.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Editor {
+    population: Population,
+    cursor: Place,
+}
+
+impl Editor {
+    /// Creates a new editor with the cursor in the center of the given population.
+    pub fn new(population: Population) -> Editor {
+        let cursor = Place::new(
+            population.get_size().get_width() / 2,
+            population.get_size().get_height() / 2);
+
+        Editor { population, cursor }
+    }
+
+    /// Applies the given command to the drawing.
+    ///
+    /// The cursor stops at the edges. Commands which do not edit the drawing are ignored.
+    pub fn apply(self, command: &EditCommand) -> Editor {
+        let x = self.cursor.get_x();
+        let y = self.cursor.get_y();
+        let max_x = self.population.get_size().get_width() - 1;
+        let max_y = self.population.get_size().get_height() - 1;
+
+        match *command {
+            EditCommand::Up => self.with_cursor(Place::new(x, y.saturating_sub(1))),
+            EditCommand::Down => self.with_cursor(Place::new(x, (y + 1).min(max_y))),
+            EditCommand::Left => self.with_cursor(Place::new(x.saturating_sub(1), y)),
+            EditCommand::Right => self.with_cursor(Place::new((x + 1).min(max_x), y)),
+            EditCommand::Toggle => {
+                let population = self.population.with_toggled_cell(&self.cursor);
+                Editor { population, ..self }
+            },
+            EditCommand::Clear => Editor { population: self.population.cleared(), ..self },
+            EditCommand::Fill => Editor { population: self.population.filled(), ..self },
+            EditCommand::Invert => Editor { population: self.population.inverted(), ..self },
+            EditCommand::Save | EditCommand::Start | EditCommand::Quit => self,
+        }
+    }
+
+    fn with_cursor(self, cursor: Place) -> Editor {
+        Editor { cursor, ..self }
+    }
+
+    /// Get the drawn population.
+    pub fn get_population(&self) -> &Population {
+        &self.population
+    }
+
+    /// Get the place of the cursor.
+    pub fn get_cursor(&self) -> &Place {
+        &self.cursor
+    }
+}
+
+/// Formats the population with the cursor highlighted.
+impl fmt::Display for Editor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let theme = self.population.get_theme();
+        let size = self.population.get_size();
+        let line = theme.generate_line(size.get_width());
+
+        if theme.has_border() {
+            writeln!(f, "{}", line)?;
+        }
+
+        for y in 0..size.get_height() {
+            if theme.has_border() {
+                write!(f, "{}", theme.get_side())?;
+            }
+
+            for x in 0..size.get_width() {
+                let place = Place::new(x, y);
+                let glyph = self.population.get_glyph(&place);
+
+                if place == self.cursor {
+                    write!(f, "{}{}{}", CURSOR_START, glyph, CURSOR_END)?;
+                } else {
+                    write!(f, "{}", glyph)?;
+                }
+            }
+
+            if theme.has_border() {
+                write!(f, "{}", theme.get_side())?;
+            }
+
+            writeln!(f)?;
+        }
+
+        if theme.has_border() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::theme::Theme;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn new_editor_has_cursor_in_center() {
+        let sut = Editor::new(Population::new(5, 4, Vec::new()).unwrap());
+
+        assert_that!(sut.get_cursor(), is(equal_to(&Place::new(2, 2))));
+    }
+
+    #[test]
+    fn cursor_stops_at_edges() {
+        let sut = Editor::new(Population::new(2, 2, Vec::new()).unwrap())
+            .apply(&EditCommand::Right)
+            .apply(&EditCommand::Right)
+            .apply(&EditCommand::Down)
+            .apply(&EditCommand::Down);
+
+        assert_that!(sut.get_cursor(), is(equal_to(&Place::new(1, 1))));
+
+        let sut = sut.apply(&EditCommand::Up)
+            .apply(&EditCommand::Up)
+            .apply(&EditCommand::Left)
+            .apply(&EditCommand::Left);
+
+        assert_that!(sut.get_cursor(), is(equal_to(&Place::new(0, 0))));
+    }
+
+    #[test]
+    fn toggle_cell_at_cursor() {
+        let sut = Editor::new(Population::new(3, 3, Vec::new()).unwrap())
+            .apply(&EditCommand::Toggle)
+            .apply(&EditCommand::Right)
+            .apply(&EditCommand::Toggle)
+            .apply(&EditCommand::Toggle);
+
+        assert_that!(sut.get_population().get_living_places(), is(equal_to(vec![Place::new(1, 1)])));
+    }
+
+    #[test]
+    fn clear_fill_and_invert() {
+        let sut = Editor::new(Population::new(2, 1, vec![Cell::new(Place::new(0, 0))]).unwrap());
+
+        let inverted = sut.clone().apply(&EditCommand::Invert);
+        assert_that!(inverted.get_population().get_living_places(), is(equal_to(vec![Place::new(1, 0)])));
+
+        let filled = sut.clone().apply(&EditCommand::Fill);
+        assert_that!(filled.get_population().get_living_places().len(), is(equal_to(2)));
+
+        let cleared = filled.apply(&EditCommand::Clear);
+        assert_that!(cleared.get_population().get_living_places().len(), is(equal_to(0)));
+    }
+
+    #[test]
+    fn fmt_highlights_cursor() {
+        let sut = Editor::new(Population::new(3, 1, vec![Cell::new(Place::new(1, 0))]).unwrap());
+        let expected = "+---+\n| \x1b[7m☀\x1b[0m |\n+---+\n";
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from(expected))));
+    }
+
+    #[test]
+    fn fmt_highlights_cursor_with_theme_without_border() {
+        let population = Population::new(2, 1, vec![Cell::new(Place::new(0, 0))]).unwrap()
+            .with_theme(Theme::ascii().without_border());
+        let sut = Editor::new(population);
+        let expected = "#\x1b[7m \x1b[0m\n";
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from(expected))));
+    }
+}
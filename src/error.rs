@@ -0,0 +1,163 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+static OPTION_ERROR_PREFIX: &str = "Bad option:";
+
+/// This enum describes why the value of an option is rejected.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Reason {
+    /// A not negative number was expected.
+    NotNumber,
+    /// The number is smaller than the given minimum.
+    BelowMinimum(usize),
+    /// The number is larger than the given maximum.
+    AboveMaximum(usize),
+    /// The option can not be used together with the given other option.
+    Conflict(String),
+    /// The value is malformed for the given reason.
+    Invalid(String),
+}
+
+/// This enum describes everything that can go wrong while configuring and running the game.
+#[derive(Debug)]
+pub enum Error {
+    /// A command line option has a bad value.
+    Option {
+        /// Name of the option without leading dashes.
+        name: String,
+        /// The given value, none for flags.
+        value: Option<String>,
+        reason: Reason,
+    },
+    /// A pattern can not be parsed.
+    Pattern {
+        /// The file the pattern was loaded from, if any.
+        file: Option<String>,
+        reason: String,
+    },
+    /// A file, the terminal or the output can not be read or written.
+    Io {
+        /// What could not be done, e.g. "write image file 'glider.png'".
+        action: String,
+        source: io::Error,
+    },
+}
+
+impl Error {
+    /// Creates an error about the given option with the given value.
+    pub fn option(name: &str, value: Option<&str>, reason: Reason) -> Error {
+        Error::Option {
+            name: String::from(name),
+            value: value.map(String::from),
+            reason,
+        }
+    }
+
+    /// Creates an error about a pattern, optionally loaded from the given file.
+    pub fn pattern(file: Option<&str>, reason: String) -> Error {
+        Error::Pattern { file: file.map(String::from), reason }
+    }
+
+    /// Creates an error about the given action which failed with the given I/O error.
+    pub fn io(action: String, source: io::Error) -> Error {
+        Error::Io { action, source }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Option { ref name, ref reason, .. } => match *reason {
+                Reason::NotNumber =>
+                    write!(f, "{} Not negative number expected as option '--{}'!", OPTION_ERROR_PREFIX, name),
+                Reason::BelowMinimum(min) =>
+                    write!(f, "{} Too small value for option '--{}' given! Minimum is {}.", OPTION_ERROR_PREFIX, name, min),
+                Reason::AboveMaximum(max) =>
+                    write!(f, "{} Too large value for option '--{}' given! Maximum is {}.", OPTION_ERROR_PREFIX, name, max),
+                Reason::Conflict(ref other) =>
+                    write!(f, "{} Option '--{}' can not be used together with option '--{}'!", OPTION_ERROR_PREFIX, name, other),
+                Reason::Invalid(ref reason) =>
+                    write!(f, "{} Invalid value for option '--{}' given! {}", OPTION_ERROR_PREFIX, name, reason),
+            },
+            Error::Pattern { file: Some(ref file), ref reason } => write!(f, "Can not parse pattern file '{}': {}", file, reason),
+            Error::Pattern { file: None, ref reason } => write!(f, "Can not parse pattern: {}", reason),
+            Error::Io { ref action, ref source } => write!(f, "Can not {}: {}!", action, source),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io { ref source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// I/O errors are equal if they are of the same kind, because `io::Error` itself can not be
+/// compared.
+impl PartialEq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        match (self, other) {
+            (Error::Option { name, value, reason }, Error::Option { name: other_name, value: other_value, reason: other_reason }) =>
+                name == other_name && value == other_value && reason == other_reason,
+            (Error::Pattern { file, reason }, Error::Pattern { file: other_file, reason: other_reason }) =>
+                file == other_file && reason == other_reason,
+            (Error::Io { action, source }, Error::Io { action: other_action, source: other_source }) =>
+                action == other_action && source.kind() == other_source.kind(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn display_option_error() {
+        let sut = Error::option("fps", Some("2000"), Reason::AboveMaximum(1000));
+
+        assert_that!(
+            sut.to_string(),
+            is(equal_to(String::from("Bad option: Too large value for option '--fps' given! Maximum is 1000."))));
+    }
+
+    #[test]
+    fn display_conflicting_options() {
+        let sut = Error::option("interactive", None, Reason::Conflict(String::from("headless")));
+
+        assert_that!(
+            sut.to_string(),
+            is(equal_to(String::from("Bad option: Option '--interactive' can not be used together with option '--headless'!"))));
+    }
+
+    #[test]
+    fn display_pattern_error() {
+        let sut = Error::pattern(Some("glider.rle"), String::from("Missing header line!"));
+
+        assert_that!(
+            sut.to_string(),
+            is(equal_to(String::from("Can not parse pattern file 'glider.rle': Missing header line!"))));
+    }
+
+    #[test]
+    fn display_io_error() {
+        let sut = Error::io(String::from("write output"), io::Error::other("broken pipe"));
+
+        assert_that!(sut.to_string(), is(equal_to(String::from("Can not write output: broken pipe!"))));
+    }
+
+    #[test]
+    fn io_error_has_source() {
+        let sut = Error::io(String::from("read pattern file 'glider.rle'"), io::Error::from(io::ErrorKind::NotFound));
+
+        match error::Error::source(&sut).and_then(|source| source.downcast_ref::<io::Error>()) {
+            Some(source) => assert_that!(source.kind(), is(equal_to(io::ErrorKind::NotFound))),
+            None => panic!("Expected I/O error as source!"),
+        }
+    }
+}
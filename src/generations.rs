@@ -0,0 +1,78 @@
+use crate::population::Population;
+use crate::rule::Rule;
+
+/// This struct steps a population through its generations by applying a rule.
+///
+/// As iterator it yields each following generation endlessly, starting with the next one. Use
+/// `take` or `take_while` to stop it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Generations {
+    population: Population,
+    rule: Rule,
+}
+
+impl Generations {
+    /// Creates a stepper starting at the given population.
+    pub fn new(population: Population, rule: Rule) -> Generations {
+        Generations { population, rule }
+    }
+
+    /// Get the current generation.
+    pub fn get_population(&self) -> &Population {
+        &self.population
+    }
+
+    /// Get the rule which decides about birth and survival of cells.
+    pub fn get_rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    /// Advances to the next generation without copying it.
+    pub fn advance(&mut self) {
+        self.population = self.population.next_generation(&self.rule);
+    }
+
+    /// Ends stepping and returns the current generation.
+    pub fn into_population(self) -> Population {
+        self.population
+    }
+}
+
+impl Iterator for Generations {
+    type Item = Population;
+
+    fn next(&mut self) -> Option<Population> {
+        self.advance();
+        Some(self.population.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::population::create_blinker;
+    use crate::place::Place;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn iterate_yields_following_generations() {
+        let sut = Generations::new(create_blinker(), Rule::conway());
+        let iterations: Vec<usize> = sut.take(3).map(|population| population.get_status().get_iteration()).collect();
+
+        assert_that!(iterations, is(equal_to(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn advance_steps_current_generation() {
+        let mut sut = Generations::new(create_blinker(), Rule::conway());
+        sut.advance();
+
+        assert_that!(
+            sut.get_population().get_living_places(),
+            is(equal_to(vec![Place::new(1, 0), Place::new(1, 1), Place::new(1, 2)])));
+
+        sut.advance();
+
+        assert_that!(sut.into_population().get_living_places(), is(equal_to(create_blinker().get_living_places())));
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Duration;
+use crate::image::{Color, Image};
+
+/// Maximum number of colours of a GIF.
+const MAX_COLORS: usize = 256;
+/// Number of bits of the colour indexes the LZW compression starts with.
+const MIN_CODE_SIZE: u8 = 8;
+/// Code which resets the LZW dictionary.
+const CLEAR_CODE: u16 = 1 << MIN_CODE_SIZE;
+/// Code which ends the image data.
+const END_CODE: u16 = CLEAR_CODE + 1;
+/// Maximum number of bits of a LZW code.
+const MAX_CODE_SIZE: u8 = 12;
+/// Shortest frame delay in hundredths of a second, most viewers slow down shorter ones.
+const MIN_DELAY: u16 = 2;
+
+/// This struct collects frames of an animated GIF.
+///
+/// Each frame is compressed when it is added, so only the compressed data is kept. All frames
+/// share one colour table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gif {
+    width: usize,
+    height: usize,
+    /// Time each frame is shown in hundredths of a second.
+    delay: u16,
+    /// Whether the animation starts over after the last frame.
+    repeat: bool,
+    palette: Vec<Color>,
+    frames: Vec<Vec<u8>>,
+}
+
+impl Gif {
+    /// Creates an animation of the given size in pixels, which shows each frame for the given
+    /// duration.
+    pub fn new(width: usize, height: usize, delay: Duration) -> Gif {
+        let delay = (delay.as_millis() / 10).clamp(u128::from(MIN_DELAY), u128::from(u16::MAX)) as u16;
+
+        Gif { width, height, delay, repeat: false, palette: Vec::new(), frames: Vec::new() }
+    }
+
+    /// Creates a copy of this animation which starts over after the last frame.
+    pub fn with_loop(self) -> Gif {
+        Gif { repeat: true, ..self }
+    }
+
+    /// Adds the given image as next frame.
+    ///
+    /// If the animation already has 256 colours, new ones are replaced by the most similar one.
+    pub fn add_frame(&mut self, image: &Image) {
+        let indexes: Vec<u8> = image.get_pixels().iter().map(|&color| self.get_index(color)).collect();
+        self.frames.push(compress(&indexes));
+    }
+
+    /// Number of frames added so far.
+    pub fn get_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Encodes the animation as GIF89a.
+    ///
+    /// Fails if the animation is wider or higher than the 65535 pixels a GIF can describe.
+    pub fn encode(&self) -> Result<Vec<u8>, String> {
+        let (width, height) = match (u16::try_from(self.width), u16::try_from(self.height)) {
+            (Ok(width), Ok(height)) => (width.to_le_bytes(), height.to_le_bytes()),
+            _ => return Err(format!(
+                "Animation of {}x{} pixels is too large for GIF! Maximum is {}x{}.",
+                self.width, self.height, u16::MAX, u16::MAX)),
+        };
+        let palette_bits = get_palette_bits(self.palette.len());
+        let mut gif = b"GIF89a".to_vec();
+
+        // Logical screen with a global colour table, 8 bit colour resolution and no background.
+        gif.extend_from_slice(&width);
+        gif.extend_from_slice(&height);
+        gif.extend_from_slice(&[0xf0 | (palette_bits - 1), 0, 0]);
+
+        for index in 0..1 << palette_bits {
+            let (red, green, blue) = self.palette.get(index).cloned().unwrap_or((0, 0, 0));
+            gif.extend_from_slice(&[red, green, blue]);
+        }
+
+        if self.repeat {
+            // The Netscape extension repeating the animation endlessly.
+            gif.extend_from_slice(&[0x21, 0xff, 0x0b]);
+            gif.extend_from_slice(b"NETSCAPE2.0");
+            gif.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+        }
+
+        for frame in &self.frames {
+            // Graphic control extension with the delay of the frame.
+            gif.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+            gif.extend_from_slice(&self.delay.to_le_bytes());
+            gif.extend_from_slice(&[0x00, 0x00]);
+
+            // Image descriptor covering the whole screen without local colour table.
+            gif.push(0x2c);
+            gif.extend_from_slice(&[0, 0, 0, 0]);
+            gif.extend_from_slice(&width);
+            gif.extend_from_slice(&height);
+            gif.push(0);
+
+            gif.push(MIN_CODE_SIZE);
+
+            for block in frame.chunks(255) {
+                gif.push(block.len() as u8);
+                gif.extend_from_slice(block);
+            }
+
+            gif.push(0);
+        }
+
+        gif.push(0x3b);
+        Ok(gif)
+    }
+
+    /// The index of the given colour in the colour table, which is added if missing.
+    fn get_index(&mut self, color: Color) -> u8 {
+        if let Some(index) = self.palette.iter().position(|&known| known == color) {
+            return index as u8;
+        }
+
+        if self.palette.len() < MAX_COLORS {
+            self.palette.push(color);
+            return (self.palette.len() - 1) as u8;
+        }
+
+        let distance = |(red, green, blue): Color| {
+            let square = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2);
+            square(red, color.0) + square(green, color.1) + square(blue, color.2)
+        };
+
+        (0..self.palette.len()).min_by_key(|&index| distance(self.palette[index])).unwrap_or(0) as u8
+    }
+}
+
+/// Number of bits needed to index the given number of colours, at least one.
+fn get_palette_bits(colors: usize) -> u8 {
+    let mut bits = 1;
+
+    while (1 << bits) < colors {
+        bits += 1;
+    }
+
+    bits
+}
+
+/// Compresses colour indexes with variable length LZW codes as GIF image data.
+fn compress(indexes: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut code_size = MIN_CODE_SIZE + 1;
+    let mut next_code = END_CODE + 1;
+
+    writer.write(CLEAR_CODE, code_size);
+
+    let mut prefix = match indexes.first() {
+        Some(&index) => u16::from(index),
+        None => {
+            writer.write(END_CODE, code_size);
+            return writer.finish();
+        },
+    };
+
+    for &index in &indexes[1..] {
+        if let Some(&code) = dictionary.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+
+        writer.write(prefix, code_size);
+
+        if next_code < 1 << MAX_CODE_SIZE {
+            dictionary.insert((prefix, index), next_code);
+
+            // The decoder needs a wider code as soon as the new code does not fit anymore.
+            if next_code == 1 << code_size {
+                code_size += 1;
+            }
+
+            next_code += 1;
+        } else {
+            writer.write(CLEAR_CODE, code_size);
+            dictionary.clear();
+            code_size = MIN_CODE_SIZE + 1;
+            next_code = END_CODE + 1;
+        }
+
+        prefix = u16::from(index);
+    }
+
+    writer.write(prefix, code_size);
+
+    // The decoder adds one more code for the last one, which may widen the end code.
+    if next_code == 1 << code_size && code_size < MAX_CODE_SIZE {
+        code_size += 1;
+    }
+
+    writer.write(END_CODE, code_size);
+    writer.finish()
+}
+
+/// This struct packs codes into bytes, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter { bytes: Vec::new(), buffer: 0, bits: 0 }
+    }
+
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::image::ImageStyle;
+    use crate::place::Place;
+    use crate::population::Population;
+    use hamcrest::prelude::*;
+
+    /// Decompresses GIF image data as described in the GIF89a specification.
+    fn decompress(data: &[u8]) -> Vec<u8> {
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut code_size = MIN_CODE_SIZE + 1;
+        let mut previous: Option<usize> = None;
+        let mut output = Vec::new();
+        let mut position = 0;
+
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..=255).map(|index| vec![index as u8]).collect();
+            table.push(Vec::new());
+            table.push(Vec::new());
+        };
+        reset(&mut table);
+
+        loop {
+            let mut code = 0;
+
+            for bit in 0..code_size as usize {
+                let byte = data[(position + bit) / 8];
+                code |= (((byte >> ((position + bit) % 8)) & 1) as usize) << bit;
+            }
+
+            position += code_size as usize;
+
+            if code == CLEAR_CODE as usize {
+                reset(&mut table);
+                code_size = MIN_CODE_SIZE + 1;
+                previous = None;
+                continue;
+            }
+
+            if code == END_CODE as usize {
+                return output;
+            }
+
+            let entry = match previous {
+                None => table[code].clone(),
+                Some(previous) => {
+                    let entry = if code < table.len() {
+                        table[code].clone()
+                    } else {
+                        let mut entry = table[previous].clone();
+                        entry.push(table[previous][0]);
+                        entry
+                    };
+                    let mut added = table[previous].clone();
+                    added.push(entry[0]);
+                    table.push(added);
+
+                    if table.len() == 1 << code_size && code_size < MAX_CODE_SIZE {
+                        code_size += 1;
+                    }
+
+                    entry
+                },
+            };
+
+            output.extend_from_slice(&entry);
+            previous = Some(code);
+        }
+    }
+
+    #[test]
+    fn compress_round_trip() {
+        let indexes: Vec<u8> = (0..20_000_u32).map(|index| ((index * index / 7) % 5) as u8).collect();
+
+        assert_that!(decompress(&compress(&indexes)), is(equal_to(indexes.clone())));
+    }
+
+    #[test]
+    fn compress_round_trip_of_random_indexes_resets_dictionary() {
+        let indexes: Vec<u8> = (0..50_000_u32).map(|index| (index.wrapping_mul(2_654_435_761) >> 24) as u8).collect();
+
+        assert_that!(decompress(&compress(&indexes)), is(equal_to(indexes.clone())));
+    }
+
+    #[test]
+    fn compress_single_index() {
+        assert_that!(decompress(&compress(&[3])), is(equal_to(vec![3])));
+    }
+
+    #[test]
+    fn get_palette_bits_covers_colors() {
+        assert_that!(get_palette_bits(0), is(equal_to(1)));
+        assert_that!(get_palette_bits(2), is(equal_to(1)));
+        assert_that!(get_palette_bits(3), is(equal_to(2)));
+        assert_that!(get_palette_bits(256), is(equal_to(8)));
+    }
+
+    #[test]
+    fn new_clamps_delay() {
+        assert_that!(Gif::new(1, 1, Duration::from_millis(1)).delay, is(equal_to(MIN_DELAY)));
+        assert_that!(Gif::new(1, 1, Duration::from_millis(250)).delay, is(equal_to(25)));
+    }
+
+    #[test]
+    fn encode_too_large_animation() {
+        let sut = Gif::new(65536, 1, Duration::from_millis(100));
+
+        assert_that!(
+            sut.encode(),
+            is(equal_to(Err(String::from("Animation of 65536x1 pixels is too large for GIF! Maximum is 65535x65535.")))));
+    }
+
+    #[test]
+    fn encode_looping_animation() {
+        let population = Population::new(2, 1, vec![Cell::new(Place::new(0, 0))]).unwrap();
+        let image = Image::of(&population, &ImageStyle::new().with_scale(1));
+        let mut sut = Gif::new(2, 1, Duration::from_millis(100)).with_loop();
+        sut.add_frame(&image);
+        sut.add_frame(&image);
+        let gif = sut.encode().unwrap();
+
+        assert_that!(sut.get_frames(), is(equal_to(2)));
+        assert_that!(&gif[..13], is(equal_to(&[b'G', b'I', b'F', b'8', b'9', b'a', 2, 0, 1, 0, 0xf0, 0, 0][..])));
+        // Black and white are the only colours.
+        assert_that!(&gif[13..19], is(equal_to(&[0, 0, 0, 255, 255, 255][..])));
+        assert_that!(&gif[22..33], is(equal_to(&b"NETSCAPE2.0"[..])));
+        assert_that!(gif[gif.len() - 1], is(equal_to(0x3b)));
+    }
+}
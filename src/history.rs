@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+use crate::place::Place;
+use crate::population::{self, Population};
+use crate::termination::Termination;
+
+/// This struct remembers the states of the recent generations to detect when a population has
+/// stopped evolving.
+///
+/// Generations are looked up by a hash of their living cells. The places of the living cells are
+/// kept as well, so a hash collision is not mistaken for a repetition.
+#[derive(Debug, PartialEq, Clone)]
+pub struct History {
+    /// How many generations are remembered. This is the longest detectable period.
+    window: usize,
+    /// The remembered generations, oldest first.
+    states: VecDeque<State>,
+}
+
+impl History {
+    /// Creates a new empty history remembering the given number of generations, but at least one.
+    pub fn new(window: usize) -> History {
+        let window = window.max(1);
+
+        History { window, states: VecDeque::with_capacity(window) }
+    }
+
+    /// Remembers the given generation and checks whether the population has stopped evolving.
+    ///
+    /// Returns how the population ended, if it is extinct or repeats a remembered generation.
+    pub fn record(&mut self, population: &Population) -> Option<Termination> {
+        let status = population.get_status();
+        let generation = status.get_iteration();
+
+        if status.get_cells() == 0 {
+            return Some(Termination::Extinct { generation });
+        }
+
+        let places = population.get_living_places();
+        let hash = population::hash_places(&places);
+        let repeated = self.states.iter()
+            .rev()
+            .find(|previous| previous.hash == hash && previous.places == places)
+            .map(|previous| previous.generation);
+
+        if let Some(start) = repeated {
+            let period = generation - start;
+
+            return Some(if period == 1 {
+                Termination::StillLife { generation: start }
+            } else {
+                Termination::Oscillator { period, generation: start }
+            });
+        }
+
+        if self.states.len() == self.window {
+            self.states.pop_front();
+        }
+
+        self.states.push_back(State { hash, places, generation });
+        None
+    }
+}
+
+/// The living cells of a remembered generation.
+#[derive(Debug, PartialEq, Clone)]
+struct State {
+    hash: u64,
+    /// The places of the living cells ordered by row and column.
+    places: Vec<Place>,
+    generation: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+    use crate::cell::Cell;
+    use crate::place::Place;
+    use crate::rule::Rule;
+    use crate::topology::Topology;
+
+    fn run(mut population: Population, window: usize, max: usize) -> Option<Termination> {
+        let mut sut = History::new(window);
+
+        for _ in 0..max {
+            if let Some(termination) = sut.record(&population) {
+                return Some(termination);
+            }
+
+            population = population.next_generation(&Rule::conway());
+        }
+
+        None
+    }
+
+    fn cells(places: &[(usize, usize)]) -> Vec<Cell> {
+        places.iter().map(|&(x, y)| Cell::new(Place::new(x, y))).collect()
+    }
+
+    #[test]
+    fn detect_extinct_population() {
+        let population = Population::new(5, 5, cells(&[(1, 1), (2, 1)])).unwrap();
+
+        assert_that!(run(population, 10, 10), is(equal_to(Some(Termination::Extinct { generation: 1 }))));
+    }
+
+    #[test]
+    fn detect_empty_initial_population() {
+        let population = Population::new(5, 5, Vec::new()).unwrap();
+
+        assert_that!(run(population, 10, 10), is(equal_to(Some(Termination::Extinct { generation: 0 }))));
+    }
+
+    #[test]
+    fn detect_still_life() {
+        // An L-tromino becomes a block in the first generation.
+        let population = Population::new(5, 5, cells(&[(1, 1), (2, 1), (1, 2)])).unwrap();
+
+        assert_that!(run(population, 10, 10), is(equal_to(Some(Termination::StillLife { generation: 1 }))));
+    }
+
+    #[test]
+    fn zero_window_remembers_one_generation() {
+        let population = Population::new(5, 5, cells(&[(1, 1), (2, 1), (1, 2)])).unwrap();
+        let mut sut = History::new(0);
+        sut.record(&population);
+
+        assert_that!(sut.states.len(), is(equal_to(1)));
+        assert_that!(run(population, 0, 10), is(equal_to(Some(Termination::StillLife { generation: 1 }))));
+    }
+
+    #[test]
+    fn detect_blinker() {
+        let population = Population::new(5, 5, cells(&[(1, 2), (2, 2), (3, 2)])).unwrap();
+
+        assert_that!(
+            run(population, 10, 10),
+            is(equal_to(Some(Termination::Oscillator { period: 2, generation: 0 }))));
+    }
+
+    #[test]
+    fn detect_nothing_if_period_exceeds_window() {
+        let population = Population::new(5, 5, cells(&[(1, 2), (2, 2), (3, 2)])).unwrap();
+
+        assert_that!(run(population, 1, 10), is(equal_to(None)));
+    }
+
+    #[test]
+    fn detect_nothing_while_evolving() {
+        let population = Population::new(20, 20, glider()).unwrap();
+
+        assert_that!(run(population, 10, 10), is(equal_to(None)));
+    }
+
+    #[test]
+    fn detect_nothing_on_hash_collision() {
+        let population = Population::new(5, 5, cells(&[(1, 2), (2, 2), (3, 2)])).unwrap();
+        let mut sut = History::new(10);
+        sut.states.push_back(State { hash: population.get_state_hash(), places: vec![Place::new(0, 0)], generation: 0 });
+
+        assert_that!(sut.record(&population), is(equal_to(None)));
+    }
+
+    #[test]
+    fn detect_glider_returning_on_torus() {
+        // A glider moves one place diagonally every four generations.
+        let population = Population::new(5, 5, glider()).unwrap().with_topology(Topology::Torus);
+
+        assert_that!(
+            run(population, 30, 30),
+            is(equal_to(Some(Termination::Oscillator { period: 20, generation: 0 }))));
+    }
+
+    fn glider() -> Vec<Cell> {
+        cells(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)])
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use crate::error::Error;
+use crate::place::Place;
+use crate::png;
+use crate::population::Population;
+
+/// A colour given by red, green and blue.
+pub type Color = (u8, u8, u8);
+
+/// Number of pixels per place in each direction used if no scale is given.
+pub const DEFAULT_SCALE: usize = 4;
+
+/// This enum describes the supported image file formats.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ImageFormat {
+    /// Portable bitmap (`.pbm`): dark places are black, all others white.
+    Pbm,
+    /// Portable graymap (`.pgm`): the brightness of the colours.
+    Pgm,
+    /// Portable Network Graphics (`.png`): the full colours.
+    Png,
+}
+
+impl ImageFormat {
+    /// Parses an image format from its name (`pbm`, `pgm` or `png`).
+    pub fn parse(name: &str) -> Result<ImageFormat, String> {
+        match name.trim().to_lowercase().as_str() {
+            "pbm" => Ok(ImageFormat::Pbm),
+            "pgm" => Ok(ImageFormat::Pgm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("Unknown image format '{}'! Expected 'pbm', 'pgm' or 'png'.", name)),
+        }
+    }
+
+    /// Detects the format from the extension of the given file name.
+    pub fn from_extension(file: &str) -> Option<ImageFormat> {
+        let extension = Path::new(file).extension()?.to_str()?;
+
+        ImageFormat::parse(extension).ok()
+    }
+
+    /// Encodes an image in this format.
+    pub fn write(&self, image: &Image) -> Vec<u8> {
+        match *self {
+            ImageFormat::Pbm => image.to_pbm(),
+            ImageFormat::Pgm => image.to_pgm(),
+            ImageFormat::Png => image.to_png(),
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ImageFormat::Pbm => write!(f, "pbm"),
+            ImageFormat::Pgm => write!(f, "pgm"),
+            ImageFormat::Png => write!(f, "png"),
+        }
+    }
+}
+
+/// This struct describes how a population is drawn into an image.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImageStyle {
+    /// Number of pixels per place in each direction.
+    scale: usize,
+    /// Whether places are separated by grid lines.
+    grid: bool,
+    alive: Color,
+    dying: Color,
+    empty: Color,
+    grid_color: Color,
+}
+
+impl ImageStyle {
+    /// Creates a style with black living cells, grey dying cells on white and without grid.
+    pub fn new() -> ImageStyle {
+        ImageStyle {
+            scale: DEFAULT_SCALE,
+            grid: false,
+            alive: (0, 0, 0),
+            dying: (160, 160, 160),
+            empty: (255, 255, 255),
+            grid_color: (208, 208, 208),
+        }
+    }
+
+    /// Creates a copy of this style with the given number of pixels per place, but at least one.
+    pub fn with_scale(self, scale: usize) -> ImageStyle {
+        ImageStyle { scale: scale.max(1), ..self }
+    }
+
+    /// Creates a copy of this style which separates places by grid lines.
+    pub fn with_grid(self) -> ImageStyle {
+        ImageStyle { grid: true, ..self }
+    }
+
+    /// Creates a copy of this style with the given colours of living cells, dying cells, empty
+    /// places and grid lines.
+    pub fn with_colors(self, alive: Color, dying: Color, empty: Color, grid_color: Color) -> ImageStyle {
+        ImageStyle { alive, dying, empty, grid_color, ..self }
+    }
+
+    /// Number of pixels the given number of places take, including the grid lines around them.
+    fn get_pixels(&self, places: usize) -> usize {
+        if self.grid {
+            places * (self.scale + 1) + 1
+        } else {
+            places * self.scale
+        }
+    }
+
+    /// The place a pixel belongs to or none if it is part of a grid line.
+    fn get_place(&self, pixel: usize) -> Option<usize> {
+        if !self.grid {
+            Some(pixel / self.scale)
+        } else if pixel.is_multiple_of(self.scale + 1) {
+            None
+        } else {
+            Some(pixel / (self.scale + 1))
+        }
+    }
+}
+
+impl Default for ImageStyle {
+    fn default() -> ImageStyle {
+        ImageStyle::new()
+    }
+}
+
+/// This struct holds the pixels of a drawn population, row by row.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    /// Draws the given population in the given style.
+    pub fn of(population: &Population, style: &ImageStyle) -> Image {
+        let width = style.get_pixels(population.get_size().get_width());
+        let height = style.get_pixels(population.get_size().get_height());
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let color = match (style.get_place(x), style.get_place(y)) {
+                    (Some(column), Some(row)) => match population.get_cell(&Place::new(column, row)) {
+                        Some(cell) if cell.is_dead() => style.dying,
+                        Some(_) => style.alive,
+                        None => style.empty,
+                    },
+                    _ => style.grid_color,
+                };
+
+                pixels.push(color);
+            }
+        }
+
+        Image { width, height, pixels }
+    }
+
+    /// Width in pixels.
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the pixels row by row.
+    pub fn get_pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    /// Encodes this image as binary portable bitmap, dark pixels are black.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut pbm = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+
+        // An image without width has no pixels, but chunks of zero length are not allowed.
+        for row in self.pixels.chunks(self.width.max(1)) {
+            // Each row is padded to whole bytes.
+            for pixels in row.chunks(8) {
+                let mut byte = 0;
+
+                for (index, &color) in pixels.iter().enumerate() {
+                    if get_brightness(color) < 128 {
+                        byte |= 0x80 >> index;
+                    }
+                }
+
+                pbm.push(byte);
+            }
+        }
+
+        pbm
+    }
+
+    /// Encodes this image as binary portable graymap.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut pgm = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        pgm.extend(self.pixels.iter().map(|&color| get_brightness(color)));
+        pgm
+    }
+
+    /// Encodes this image as PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        png::encode(self.width, self.height, &self.pixels)
+    }
+}
+
+/// The perceived brightness of a colour.
+fn get_brightness((red, green, blue): Color) -> u8 {
+    ((299 * u32::from(red) + 587 * u32::from(green) + 114 * u32::from(blue)) / 1000) as u8
+}
+
+/// Saves the given population as image in the given style.
+///
+/// The format is chosen by the extension of the file, PNG is the default.
+pub fn save(file: &str, population: &Population, style: &ImageStyle) -> Result<(), Error> {
+    let format = ImageFormat::from_extension(file).unwrap_or(ImageFormat::Png);
+
+    fs::write(file, format.write(&Image::of(population, style)))
+        .map_err(|err| Error::io(format!("write image file '{}'", file), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use hamcrest::prelude::*;
+
+    fn create_population() -> Population {
+        Population::new(3, 1, vec![Cell::new(Place::new(0, 0)), Cell::new(Place::new(2, 0)).kill()]).unwrap()
+    }
+
+    #[test]
+    fn parse_known_image_formats() {
+        assert_that!(ImageFormat::parse("pbm"), is(equal_to(Ok(ImageFormat::Pbm))));
+        assert_that!(ImageFormat::parse("PGM"), is(equal_to(Ok(ImageFormat::Pgm))));
+        assert_that!(ImageFormat::parse("png"), is(equal_to(Ok(ImageFormat::Png))));
+    }
+
+    #[test]
+    fn parse_unknown_image_format() {
+        assert_that!(
+            ImageFormat::parse("jpg"),
+            is(equal_to(Err(String::from("Unknown image format 'jpg'! Expected 'pbm', 'pgm' or 'png'.")))));
+    }
+
+    #[test]
+    fn from_extension() {
+        assert_that!(ImageFormat::from_extension("snapshots/generation.PGM"), is(equal_to(Some(ImageFormat::Pgm))));
+        assert_that!(ImageFormat::from_extension("generation.rle"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn image_of_population_is_scaled() {
+        let sut = Image::of(&create_population(), &ImageStyle::new().with_scale(2));
+
+        assert_that!(sut.get_width(), is(equal_to(6)));
+        assert_that!(sut.get_height(), is(equal_to(2)));
+        assert_that!(sut.get_pixels()[1], is(equal_to((0, 0, 0))));
+        assert_that!(sut.get_pixels()[2], is(equal_to((255, 255, 255))));
+        assert_that!(sut.get_pixels()[11], is(equal_to((160, 160, 160))));
+    }
+
+    #[test]
+    fn image_of_population_with_grid() {
+        let sut = Image::of(&create_population(), &ImageStyle::new().with_scale(1).with_grid());
+        let grid = (208, 208, 208);
+
+        assert_that!(sut.get_width(), is(equal_to(7)));
+        assert_that!(sut.get_height(), is(equal_to(3)));
+        assert_that!(
+            &sut.get_pixels()[7..14],
+            is(equal_to(&[grid, (0, 0, 0), grid, (255, 255, 255), grid, (160, 160, 160), grid][..])));
+    }
+
+    #[test]
+    fn image_with_custom_colors() {
+        let style = ImageStyle::new()
+            .with_scale(1)
+            .with_colors((0, 255, 0), (255, 0, 0), (0, 0, 0), (9, 9, 9));
+        let sut = Image::of(&create_population(), &style);
+
+        assert_that!(sut.get_pixels(), is(equal_to(&[(0, 255, 0), (0, 0, 0), (255, 0, 0)][..])));
+    }
+
+    #[test]
+    fn to_pbm() {
+        let sut = Image::of(&create_population(), &ImageStyle::new().with_scale(3));
+        let mut expected = b"P4\n9 3\n".to_vec();
+        expected.extend_from_slice(&[0b1110_0000, 0, 0b1110_0000, 0, 0b1110_0000, 0]);
+
+        assert_that!(sut.to_pbm(), is(equal_to(expected)));
+    }
+
+    #[test]
+    fn to_pbm_without_width() {
+        let sut = Image::of(&Population::new(0, 3, Vec::new()).unwrap(), &ImageStyle::new());
+
+        assert_that!(sut.to_pbm(), is(equal_to(b"P4\n0 12\n".to_vec())));
+    }
+
+    #[test]
+    fn to_pgm() {
+        let sut = Image::of(&create_population(), &ImageStyle::new().with_scale(1));
+        let mut expected = b"P5\n3 1\n255\n".to_vec();
+        expected.extend_from_slice(&[0, 255, 160]);
+
+        assert_that!(sut.to_pgm(), is(equal_to(expected)));
+    }
+}
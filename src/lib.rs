@@ -1,75 +1,853 @@
+//! A Game of Life implementation, usable as application and as library.
+//!
+//! The simulation can be embedded without the terminal: A [`Population`] is created from cells or
+//! from a [`Pattern`], stepped with a [`Rule`] and queried for its cells and [`Status`].
+//!
+//! ```
+//! use game_of_life::{Cell, Place, Population, Rule};
+//!
+//! let blinker = Population::new(3, 3, vec![
+//!     Cell::new(Place::new(0, 1)),
+//!     Cell::new(Place::new(1, 1)),
+//!     Cell::new(Place::new(2, 1)),
+//! ]).unwrap();
+//! let next = blinker.next_generation(&Rule::conway());
+//!
+//! assert_eq!(next.get_living_places(), vec![Place::new(1, 0), Place::new(1, 1), Place::new(1, 2)]);
+//! assert_eq!(next.get_status().get_born(), 2);
+//! ```
+//!
+//! [`Generations`] iterates over the following generations:
+//!
+//! ```
+//! use game_of_life::{pattern, Rule};
+//!
+//! let glider = pattern::parse("x = 3, y = 3\nbo$2bo$3o!").unwrap().to_population(8, 8);
+//! let cells: Vec<usize> = glider.generations(&Rule::conway())
+//!     .take(4)
+//!     .map(|population| population.get_status().get_cells())
+//!     .collect();
+//!
+//! assert_eq!(cells, vec![5, 5, 5, 5]);
+//! ```
+//!
+//! [`run_game`] runs the whole application on top of this API.
+
 #[cfg(test)]
 #[macro_use]
 extern crate hamcrest;
 extern crate clap;
 extern crate rand;
+extern crate termion;
+extern crate unicode_width;
 
-mod cell;
+pub mod age;
+pub mod cell;
+pub mod clock;
 pub mod config;
-mod dimension;
-mod place;
-mod population;
+mod control;
+pub mod dimension;
+mod editor;
+pub mod error;
+mod gif;
+pub mod generations;
+pub mod history;
+pub mod image;
+mod life106;
+pub mod place;
+pub mod pattern;
+mod plaintext;
+mod png;
+pub mod population;
+mod render;
+mod recorder;
+mod renderer;
+mod rle;
+pub mod rule;
 mod screen;
-mod status;
+pub mod simulation;
+pub mod status;
+pub mod svg;
+mod terminal;
+pub mod termination;
+pub mod theme;
+pub mod topology;
+mod viewport;
+
+pub use cell::Cell;
+pub use dimension::Dimension;
+pub use error::Error;
+pub use generations::Generations;
+pub use pattern::Pattern;
+pub use place::Place;
+pub use population::Population;
+pub use rule::Rule;
+pub use status::Status;
+pub use topology::Topology;
 
-use std::thread;
-use rand::Rng;
+use std::fs;
+use std::path::Path;
+use std::io;
+use std::io::Write;
+use std::time::Duration;
+use rand::{Rng, SeedableRng, XorShiftRng};
 
-use place::Place;
-use cell::Cell;
+use clock::{Clock, SystemClock};
 use config::Config;
-use population::Population;
-use screen::{clear, print_header};
+use control::{Command, EditCommand};
+use editor::Editor;
+use recorder::Recorder;
+use render::Render;
+use screen::{clear, print_edit_key_help, print_header, print_key_help};
+use simulation::{GenerationLimit, Observer, Simulation, Step};
+use svg::SvgStyle;
+use terminal::Terminal;
+use termination::Termination;
+use viewport::Viewport;
 
 /// Author of the application.
-pub static APPLICATION_AUTHOR: &'static str = "Sven Strittmatter <ich@weltraumschaf.de>";
+pub static APPLICATION_AUTHOR: &str = "Sven Strittmatter <ich@weltraumschaf.de>";
 /// Description of the application.
-pub static APPLICATION_DESCRIPTION: &'static str = "This is a Game of Life implementation.";
+pub static APPLICATION_DESCRIPTION: &str = "This is a Game of Life implementation.";
 /// Name of the application
-pub static APPLICATION_NAME: &'static str = "game_of_life";
+pub static APPLICATION_NAME: &str = "game_of_life";
 /// Version of the application.
-pub static APPLICATION_VERSION: &'static str = "1.0.0";
+pub static APPLICATION_VERSION: &str = "1.0.0";
+
+/// How often the keyboard is polled while waiting for the next iteration in interactive mode.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs the game of life on the terminal.
+///
+/// This function may run endless, if no generation limit is configured and the population never
+/// stops evolving. The generations are stepped by a `Simulation`, which tells the observers
+/// printing, saving and recording them. In headless mode the generations are neither printed nor
+/// is there any sleep between them. In interactive mode the game is controlled by the keyboard.
+/// In edit mode the initial population is drawn by hand first. At the end a summary is printed
+/// and the reason of the termination returned.
+pub fn run_game(config: Config) -> Result<Termination, Error> {
+    run(config, &mut io::stdout(), &mut SystemClock::new(), true)
+}
+
+/// Runs the game of life like `run_game`, but prints to the given output and takes the time from
+/// the given clock.
+///
+/// The whole population is printed, because the output is not known to be a terminal. The
+/// interactive, edit and flicker-free modes still need the terminal.
+pub fn run_game_with<W: Write, C: Clock>(config: Config, out: &mut W, clock: &mut C) -> Result<Termination, Error> {
+    run(config, out, clock, false)
+}
+
+/// Runs the game, fitting the frames into the terminal if wanted.
+fn run<W: Write, C: Clock>(config: Config, out: &mut W, clock: &mut C, fit_terminal: bool) -> Result<Termination, Error> {
+    let started = clock.now();
+    let (config, mut population) = create_initial_population(config)?;
+    let mut terminal = create_terminal(&config)?;
+
+    if config.is_edit() {
+        match terminal.as_mut().and_then(|terminal| edit_population(terminal, &config, &population, &mut *clock)) {
+            Some(drawn) => population = drawn,
+            None => {
+                let termination = Termination::Quit { generation: 0 };
+                drop(terminal);
+                print_summary(out, &population, &termination, clock.now() - started)?;
+                return Ok(termination);
+            },
+        }
+    }
+
+    // A loaded pattern or drawing is kept to start over with it.
+    let initial = if config.get_pattern().is_some() || config.is_edit() {
+        Some(population.clone())
+    } else {
+        None
+    };
+    let mut simulation = Simulation::new(population, config.get_rule(), config.get_history());
+    let mut player = Player::new(config.clone(), initial, terminal, &mut *out, &mut *clock, fit_terminal);
+    let mut archive = Archive { config: config.clone(), messages: Vec::new(), dumped: false };
+    let mut recorder = create_recorder(&config);
+    let mut limit = config.get_generations().map(GenerationLimit::new);
+    let mut observers: Vec<&mut dyn Observer> = vec![&mut player, &mut archive];
+
+    if let Some(recorder) = recorder.as_mut() {
+        observers.push(recorder);
+    }
+
+    if let Some(limit) = limit.as_mut() {
+        observers.push(limit);
+    }
+
+    let termination = simulation.run(&mut observers)?;
+    drop(observers);
+
+    // Restores the terminal mode before the summary is printed.
+    drop(player);
+    let population = simulation.into_population();
+
+    for message in &archive.messages {
+        write_line(out, message)?;
+    }
+
+    // If the dump generation was never reached the final one is dumped instead.
+    if !archive.dumped {
+        if let Some(generation) = config.get_dump_generation() {
+            write_line(out, &format!("Generation {} to dump was never reached.", generation))?;
+        }
+
+        if let Some(message) = dump_population(&config, &population)? {
+            write_line(out, &message)?;
+        }
+    }
+
+    if let Some(recorder) = recorder {
+        if let Some(message) = save_recording(&config, recorder)? {
+            write_line(out, &message)?;
+        }
+    }
+
+    print_summary(out, &population, &termination, clock.now() - started)?;
+    Ok(termination)
+}
+
+/// This struct is the observer which shows the generations and lets the user control the game.
+///
+/// Without raw terminal the frames are printed to the output and the game sleeps between them.
+/// In headless mode nothing is shown at all.
+struct Player<'a, W: Write, C: Clock> {
+    config: Config,
+    /// The loaded pattern or drawing the game starts over with, random if none.
+    initial: Option<Population>,
+    terminal: Option<Terminal>,
+    out: &'a mut W,
+    clock: &'a mut C,
+    /// Whether the shown window is fitted into the size of the terminal.
+    fit_terminal: bool,
+    viewport: Viewport,
+    paused: bool,
+    /// When the previous frame was printed, if the game sleeps between frames.
+    frame_started: Option<Duration>,
+}
+
+impl<'a, W: Write, C: Clock> Player<'a, W, C> {
+    fn new(
+        config: Config,
+        initial: Option<Population>,
+        terminal: Option<Terminal>,
+        out: &'a mut W,
+        clock: &'a mut C,
+        fit_terminal: bool) -> Player<'a, W, C> {
+        let viewport = create_viewport(&config);
+
+        Player { config, initial, terminal, out, clock, fit_terminal, viewport, paused: false, frame_started: None }
+    }
+}
+
+impl<'a, W: Write, C: Clock> Observer for Player<'a, W, C> {
+    fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+        if self.config.is_headless() {
+            return Ok(Step::Continue);
+        }
+
+        if let Some(previous) = self.frame_started {
+            let elapsed = self.clock.now() - previous;
+            self.clock.sleep(remaining_frame_time(self.config.get_sleep(), elapsed));
+        }
+
+        let frame_started = self.clock.now();
+        self.print_frame(population)?;
+
+        if self.terminal.is_none() {
+            self.frame_started = Some(frame_started);
+            return Ok(Step::Continue);
+        }
+
+        match self.wait_for_user(population, frame_started)? {
+            UserAction::Advance => Ok(Step::Continue),
+            UserAction::Reseed => {
+                let (config, population) = create_restart_population(self.config.clone(), self.initial.as_ref());
+                self.config = config;
+                Ok(Step::Restart(Box::new(population)))
+            },
+            UserAction::Quit => Ok(Step::Stop(Termination::Quit { generation: population.get_status().get_iteration() })),
+        }
+    }
+}
+
+/// This struct is the observer which saves the configured generations: the dump generation and
+/// every snapshot generation.
+struct Archive {
+    config: Config,
+    /// Messages about saved files, printed after the game.
+    messages: Vec<String>,
+    /// Whether the dump generation was saved.
+    dumped: bool,
+}
+
+impl Observer for Archive {
+    fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+        let generation = population.get_status().get_iteration();
+
+        if self.config.get_dump_generation() == Some(generation) {
+            self.messages.extend(dump_population(&self.config, population)?);
+            self.dumped = true;
+        }
+
+        if let Some(every) = self.config.get_snapshot_every() {
+            if generation.is_multiple_of(every) {
+                save_snapshot(&self.config, population)?;
+            }
+        }
+
+        Ok(Step::Continue)
+    }
+}
+
+/// Creates the recorder if generations are recorded, each frame is shown for the configured
+/// sleep.
+fn create_recorder(config: &Config) -> Option<Recorder> {
+    config.get_record()?;
+
+    let recorder = Recorder::new(config.get_record_style().clone(), config.get_sleep())
+        .with_range(config.get_record_from(), config.get_record_to())
+        .with_max_frames(config.get_record_frames());
+
+    if config.is_record_loop() {
+        Some(recorder.with_loop())
+    } else {
+        Some(recorder)
+    }
+}
+
+/// Saves the recorded generations to the configured file and tells about it.
+fn save_recording(config: &Config, recorder: Recorder) -> Result<Option<String>, Error> {
+    let file = match config.get_record() {
+        Some(file) => file,
+        None => return Ok(None),
+    };
+
+    recorder.save(file)?;
+    Ok(Some(format!("Recorded {} frames to '{}'.", recorder.get_frames(), file)))
+}
+
+/// Saves the current generation as image into the snapshot directory.
+///
+/// The file is named after the generation, e.g. `generation-000042.png`.
+fn save_snapshot(config: &Config, population: &Population) -> Result<(), Error> {
+    let dir = config.get_snapshot_dir();
+    fs::create_dir_all(dir).map_err(|err| Error::io(format!("create snapshot directory '{}'", dir), err))?;
+
+    let file = Path::new(dir).join(format!(
+        "generation-{:06}.{}",
+        population.get_status().get_iteration(),
+        config.get_snapshot_format()));
+
+    image::save(&file.to_string_lossy(), population, config.get_snapshot_style())
+}
+
+/// Switches the terminal into raw mode if keys are read or frames are drawn flicker-free.
+fn create_terminal(config: &Config) -> Result<Option<Terminal>, Error> {
+    if !config.is_interactive() && !config.is_edit() && !config.is_flicker_free() {
+        return Ok(None);
+    }
+
+    let terminal = Terminal::new()?;
+
+    if config.is_flicker_free() {
+        Ok(Some(terminal.with_alternate_screen()))
+    } else {
+        Ok(Some(terminal))
+    }
+}
+
+/// Creates the viewport with the configured render and color mode and the initially configured
+/// zoom and following.
+fn create_viewport(config: &Config) -> Viewport {
+    let viewport = Viewport::new(config.get_width(), config.get_height())
+        .with_zoom(config.get_zoom())
+        .with_render(config.get_render().clone())
+        .with_color(config.get_color().clone());
+
+    if config.is_follow() {
+        viewport.with_follow()
+    } else {
+        viewport
+    }
+}
+
+/// What the game should do after waiting for the user.
+enum UserAction {
+    /// Continue with the next generation.
+    Advance,
+    /// Start over with the loaded pattern or drawing, else with a new random population.
+    Reseed,
+    /// End the game.
+    Quit,
+}
+
+impl<'a, W: Write, C: Clock> Player<'a, W, C> {
+    /// Prints the whole screen for the current generation.
+    ///
+    /// If the terminal is in raw mode the screen is printed through it. In interactive mode the
+    /// key help is shown. Only the window of the population which fits into the terminal is shown.
+    fn print_frame(&mut self, population: &Population) -> Result<(), Error> {
+        let mut frame = String::new();
+        print_header(&mut frame);
+
+        if self.config.is_interactive() {
+            print_key_help(&mut frame, self.paused);
+        }
+
+        frame.push_str(&format!("{}\n{}\n", self.config, population.get_status()));
+
+        let size = if self.fit_terminal { termion::terminal_size().ok() } else { None };
+        fit_viewport(&mut self.viewport, population, frame.lines().count(), size);
+
+        if !self.viewport.shows_all(population.get_size()) {
+            frame.push_str(&format!("{}\n", self.viewport));
+        }
+
+        frame.push('\n');
+        frame.push_str(&self.viewport.render(population));
+
+        match self.terminal.as_mut() {
+            Some(terminal) => {
+                terminal.draw(&frame);
+                Ok(())
+            },
+            None => {
+                let mut screen = String::new();
+                clear(&mut screen);
+                write!(self.out, "{}{}", screen, frame)
+                    .and_then(|_| self.out.flush())
+                    .map_err(error_output)
+            },
+        }
+    }
 
-/// Runs the game of life.
-/// This function may run endless.
-pub fn run_game(config: Config) {
-    let mut population = create_initial_population(&config);
-    let mut previous_status = population.get_status();
+    /// Handles the keys pressed until the current iteration is over.
+    ///
+    /// A running game advances when the configured iteration duration has passed. A paused one
+    /// only advances on the step key. If the game is not interactive only the quit keys are
+    /// handled.
+    fn wait_for_user(&mut self, population: &Population, frame_started: Duration) -> Result<UserAction, Error> {
+        loop {
+            let command = match self.terminal.as_mut() {
+                Some(terminal) => terminal.poll_command(),
+                None => return Ok(UserAction::Advance),
+            };
+
+            if let Some(command) = command {
+                if !self.config.is_interactive() && command != Command::Quit {
+                    continue;
+                }
+
+                match command {
+                    Command::TogglePause => self.paused = !self.paused,
+                    Command::Step => return Ok(UserAction::Advance),
+                    Command::Faster => self.config = self.config.clone().faster(),
+                    Command::Slower => self.config = self.config.clone().slower(),
+                    Command::Reseed => return Ok(UserAction::Reseed),
+                    Command::Pan(columns, rows) => self.viewport.pan(columns, rows),
+                    Command::ZoomIn => self.viewport.zoom_in(),
+                    Command::ZoomOut => self.viewport.zoom_out(population.get_size()),
+                    Command::ToggleFollow => self.viewport.toggle_follow(),
+                    Command::Quit => return Ok(UserAction::Quit),
+                }
+
+                self.print_frame(population)?;
+                continue;
+            }
+
+            if !self.paused && self.clock.now() - frame_started >= self.config.get_sleep() {
+                return Ok(UserAction::Advance);
+            }
+
+            self.clock.sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Lines of a frame besides the given ones above the population: The viewport line, an empty line,
+/// the frame around the population and the line the cursor ends up in.
+const FRAME_LINES: usize = 5;
+
+/// Resizes the viewport to the space left below the given number of lines in a terminal of the
+/// given size.
+///
+/// If the size of the terminal is unknown, e.g. because the output is redirected, the whole
+/// population is shown.
+fn fit_viewport(viewport: &mut Viewport, population: &Population, lines: usize, size: Option<(u16, u16)>) {
+    match size {
+        Some((columns, rows)) if columns > 0 && rows > 0 => {
+            let theme = population.get_theme();
+            let border = if theme.has_border() { 2 } else { 0 };
+            // Wide glyphs of a theme take several terminal columns per shown character.
+            let width = if *viewport.get_render() == Render::Cells { theme.get_width() } else { 1 };
+
+            viewport.resize(
+                (columns as usize).saturating_sub(border) / width,
+                (rows as usize).saturating_sub(lines + FRAME_LINES));
+        },
+        _ => viewport.resize(population.get_size().get_width(), population.get_size().get_height()),
+    }
+
+    viewport.adjust_to(population);
+}
+
+/// Lets the user draw the population until the simulation is started.
+///
+/// Returns `None` if the user quits without simulation. Between the key presses the given clock
+/// waits.
+fn edit_population<C: Clock>(terminal: &mut Terminal, config: &Config, population: &Population, clock: &mut C) -> Option<Population> {
+    let mut editor = Editor::new(population.clone());
+    let mut message = String::new();
+    print_editor(terminal, config, &editor, &message);
 
     loop {
-        clear();
-        print_header();
-        println!("{}", &config);
-        println!("{}", population.get_status());
-        println!();
-        print!("{}", population);
-        population = population.next_generation();
+        let command = match terminal.poll_key().and_then(|key| EditCommand::from_key(&key)) {
+            Some(command) => command,
+            None => {
+                clock.sleep(POLL_INTERVAL);
+                continue;
+            },
+        };
 
-        if previous_status.is_population_unchanged(population.get_status()) {
-            println!("Population is stuck! No more evolution...");
-            break;
+        match command {
+            EditCommand::Start => return Some(editor.get_population().clone()),
+            EditCommand::Quit => return None,
+            EditCommand::Save => {
+                message = match save_drawing(config, editor.get_population()) {
+                    Ok(()) => format!("Saved drawing to '{}'.", config.get_drawing_file()),
+                    Err(err) => err.to_string(),
+                };
+            },
+            _ => editor = editor.apply(&command),
         }
 
-        previous_status = population.get_status();
-        thread::sleep(config.get_sleep());
+        print_editor(terminal, config, &editor, &message);
+    }
+}
+
+/// Prints the whole screen of the editor with an optional message below the drawing.
+fn print_editor(terminal: &mut Terminal, config: &Config, editor: &Editor, message: &str) {
+    let mut frame = String::new();
+    print_header(&mut frame);
+    print_edit_key_help(&mut frame);
+    frame.push_str(&format!(
+        "{}\nCursor: {}, Cells: {}\n\n{}{}\n",
+        config,
+        editor.get_cursor(),
+        editor.get_population().get_status().get_cells(),
+        editor,
+        message));
+    terminal.draw(&frame);
+}
+
+/// Saves the drawn population to the drawing file.
+fn save_drawing(config: &Config, population: &Population) -> Result<(), Error> {
+    let file = config.get_drawing_file();
+
+    if svg::is_svg(file) {
+        return svg::save(file, population, &config.get_rule(), &SvgStyle::new());
+    }
+
+    let pattern = Pattern::from_population(population)
+        .with_comments(vec![format!("Drawn with {} {}.", APPLICATION_NAME, APPLICATION_VERSION)])
+        .with_rule(config.get_rule());
+
+    pattern::save(file, &pattern)
+}
+
+/// Calculates how long to sleep so that a frame lasts the given duration, although computing and
+/// printing it already took the elapsed time.
+fn remaining_frame_time(frame: Duration, elapsed: Duration) -> Duration {
+    frame.checked_sub(elapsed).unwrap_or_else(|| Duration::from_secs(0))
+}
+
+/// Prints the final status, the reason of the termination and the elapsed time.
+fn print_summary<W: Write>(out: &mut W, population: &Population, termination: &Termination, elapsed: Duration) -> Result<(), Error> {
+    writeln!(out, "{}", population.get_status())
+        .and_then(|_| writeln!(out, "{}", termination))
+        .and_then(|_| writeln!(out, "Elapsed time: {}.{:03} seconds", elapsed.as_secs(), elapsed.subsec_millis()))
+        .and_then(|_| out.flush())
+        .map_err(error_output)
+}
+
+/// Writes the given line to the output.
+fn write_line<W: Write>(out: &mut W, line: &str) -> Result<(), Error> {
+    writeln!(out, "{}", line).map_err(error_output)
+}
+
+fn error_output(err: io::Error) -> Error {
+    Error::io(String::from("write output"), err)
+}
+
+/// Saves the population to the configured dump file, if any. SVG files get a drawing, all others a
+/// pattern.
+fn dump_population(config: &Config, population: &Population) -> Result<Option<String>, Error> {
+    let file = match config.get_dump() {
+        Some(file) => file,
+        None => return Ok(None),
+    };
+
+    if svg::is_svg(file) {
+        svg::save(file, population, &config.get_rule(), &SvgStyle::new().with_dying())?;
+    } else {
+        let pattern = Pattern::from_population(population)
+            .with_comments(vec![format!(
+                "Generation {} saved by {} {}.",
+                population.get_status().get_iteration(),
+                APPLICATION_NAME,
+                APPLICATION_VERSION)])
+            .with_rule(config.get_rule());
+
+        pattern::save(file, &pattern)?;
+    }
+
+    Ok(Some(format!("Saved generation {} to '{}'.", population.get_status().get_iteration(), file)))
+}
+
+/// Creates the initial population either from the configured pattern file or randomly.
+///
+/// Returns the config adapted to the loaded pattern along with the population.
+///
+/// A population to draw by hand starts empty.
+fn create_initial_population(config: Config) -> Result<(Config, Population), Error> {
+    match config.get_pattern().cloned() {
+        Some(file) => {
+            let pattern = pattern::load(&file)?;
+            let config = config.adapt_to_pattern(&pattern);
+            let population = pattern.to_population(config.get_width(), config.get_height())
+                .with_topology(config.get_topology().clone())
+                .with_trail(config.get_trail())
+                .with_theme(config.get_theme().clone());
+
+            Ok((config, population))
+        },
+        None if config.is_edit() => {
+            let population = Population::new_unchecked(config.get_width(), config.get_height(), Vec::new())
+                .with_topology(config.get_topology().clone())
+                .with_trail(config.get_trail())
+                .with_theme(config.get_theme().clone());
+
+            Ok((config, population))
+        },
+        None => {
+            let population = create_random_population(&config);
+
+            Ok((config, population))
+        },
+    }
+}
+
+/// Creates the population the game starts over with: the given loaded pattern or drawing, or
+/// else a random population with a new seed.
+fn create_restart_population(config: Config, initial: Option<&Population>) -> (Config, Population) {
+    match initial {
+        Some(initial) => (config, initial.clone()),
+        None => {
+            let config = config.with_seed(rand::thread_rng().next_u64());
+            let population = create_random_population(&config);
+
+            (config, population)
+        },
     }
 }
 
 /// Generate a random population of cells.
-fn create_initial_population(config: &Config) -> Population {
+///
+/// The same seed, dimension and ratio always yield the same population.
+fn create_random_population(config: &Config) -> Population {
     let mut cells: Vec<Cell> = Vec::new();
-    let mut rng = rand::thread_rng();
+    let mut rng = create_seeded_rng(config.get_seed());
 
     for y in 0..config.get_height() {
         for x in 0..config.get_width() {
-            if rng.next_u32() % config.get_ratio() == 0 {
+            if rng.next_u32().is_multiple_of(config.get_ratio()) {
                 cells.push(Cell::new(Place::new(x, y)));
             }
         }
     }
 
-    Population::new(
+    Population::new_unchecked(
         config.get_width(),
         config.get_height(),
         cells)
+        .with_topology(config.get_topology().clone())
+        .with_trail(config.get_trail())
+        .with_theme(config.get_theme().clone())
+}
+
+/// Creates a random number generator with a reproducible sequence for the given seed.
+fn create_seeded_rng(seed: u64) -> XorShiftRng {
+    let low = seed as u32;
+    let high = (seed >> 32) as u32;
+
+    // The generator must not be seeded with zeros only.
+    XorShiftRng::from_seed([low, high, low ^ 0x9e37_79b9, high ^ 0x7f4a_7c15])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clock::SimulatedClock;
+    use clap::{App, Arg};
+    use hamcrest::prelude::*;
+
+    fn create_test_config(args: Vec<&str>) -> Config {
+        let matches = App::new("test")
+            .arg(Arg::with_name("width").long("width").takes_value(true))
+            .arg(Arg::with_name("height").long("height").takes_value(true))
+            .arg(Arg::with_name("ratio").long("ratio").takes_value(true))
+            .arg(Arg::with_name("seed").long("seed").takes_value(true))
+            .arg(Arg::with_name("sleep").long("sleep").takes_value(true))
+            .arg(Arg::with_name("generations").long("generations").takes_value(true))
+            .arg(Arg::with_name("headless").long("headless"))
+            .arg(Arg::with_name("dump").long("dump").takes_value(true))
+            .arg(Arg::with_name("dump-generation").long("dump-generation").takes_value(true))
+            .get_matches_from(args);
+
+        config::create_config(&matches).unwrap()
+    }
+
+    #[test]
+    fn create_random_population_is_reproducible_with_same_seed() {
+        let config = create_test_config(vec!["test", "--width", "8", "--height", "4", "--ratio", "3", "--seed", "42"]);
+        let expected = r#"+--------+
+|    ☀☀  |
+| ☀   ☀☀ |
+| ☀☀   ☀ |
+|    ☀☀☀ |
++--------+
+"#;
+
+        assert_that!(create_random_population(&config), is(equal_to(create_random_population(&config))));
+        assert_that!(format!("{}", create_random_population(&config)), is(equal_to(String::from(expected))));
+    }
+
+    #[test]
+    fn create_random_population_differs_with_other_seed() {
+        let config = create_test_config(vec!["test", "--width", "8", "--height", "4", "--ratio", "3", "--seed", "42"]);
+        let other = create_test_config(vec!["test", "--width", "8", "--height", "4", "--ratio", "3", "--seed", "43"]);
+
+        assert_that!(create_random_population(&config) == create_random_population(&other), is(false));
+    }
+
+    #[test]
+    fn create_restart_population_keeps_initial_population() {
+        let config = create_test_config(vec!["test", "--width", "8", "--height", "4", "--seed", "42"]);
+        let initial = Population::new(8, 4, vec![Cell::new(Place::new(1, 2))]).unwrap();
+
+        let (restarted_config, population) = create_restart_population(config.clone(), Some(&initial));
+
+        assert_that!(restarted_config.get_seed(), is(equal_to(42)));
+        assert_that!(population, is(equal_to(initial)));
+    }
+
+    #[test]
+    fn create_restart_population_reseeds_random_population() {
+        let config = create_test_config(vec!["test", "--width", "8", "--height", "4", "--seed", "42"]);
+
+        let (restarted_config, population) = create_restart_population(config.clone(), None);
+
+        assert_that!(restarted_config.get_seed() == 42, is(false));
+        assert_that!(population, is(equal_to(create_random_population(&restarted_config))));
+    }
+
+    fn run_test_game(args: Vec<&str>) -> (Result<Termination, Error>, String, SimulatedClock) {
+        let mut out = Vec::new();
+        let mut clock = SimulatedClock::new();
+        let termination = run_game_with(create_test_config(args), &mut out, &mut clock);
+
+        (termination, String::from_utf8(out).unwrap(), clock)
+    }
+
+    #[test]
+    fn run_game_with_prints_frames() {
+        let (termination, out, clock) = run_test_game(vec![
+            "test", "--width", "4", "--height", "3", "--ratio", "2", "--seed", "7", "--sleep", "100ms",
+            "--generations", "1"]);
+
+        let expected = concat!(
+            "\u{1b}[2J\u{1b}[1;1HGame of Life\n",
+            "============\n",
+            "\n",
+            "Width:         4, Height:     3, Sleep:   100ms, Ratio:     2, Rule: B3/S23, Topology: plane, Seed: 7\n",
+            "Iteration:     0, Cells:      6, Born:      0, Died:       0, Ages (new/young/stable/ancient): 6/0/0/0\n",
+            "\n",
+            "+----+\n",
+            "| ☀☀ |\n",
+            "| ☀ ☀|\n",
+            "|☀ ☀ |\n",
+            "+----+\n",
+            "\u{1b}[2J\u{1b}[1;1HGame of Life\n",
+            "============\n",
+            "\n",
+            "Width:         4, Height:     3, Sleep:   100ms, Ratio:     2, Rule: B3/S23, Topology: plane, Seed: 7\n",
+            "Iteration:     1, Cells:      6, Born:      2, Died:       2, Ages (new/young/stable/ancient): 2/4/0/0\n",
+            "\n",
+            "+----+\n",
+            "| ☀☀ |\n",
+            "|☀☼ ☀|\n",
+            "|☼☀☀ |\n",
+            "+----+\n",
+            "Iteration:     1, Cells:      6, Born:      2, Died:       2, Ages (new/young/stable/ancient): 2/4/0/0\n",
+            "Generation limit of 1 generations reached.\n",
+            "Elapsed time: 0.100 seconds\n");
+
+        assert_that!(termination, is(equal_to(Ok(Termination::GenerationLimit { generation: 1 }))));
+        assert_that!(out, is(equal_to(String::from(expected))));
+        assert_that!(clock.now(), is(equal_to(Duration::from_millis(100))));
+    }
+
+    #[test]
+    fn run_game_with_prints_only_summary_when_headless() {
+        let (termination, out, clock) = run_test_game(vec![
+            "test", "--width", "4", "--height", "3", "--ratio", "2", "--seed", "7", "--sleep", "100ms",
+            "--generations", "20", "--headless"]);
+        let expected = concat!(
+            "Iteration:     2, Cells:      6, Born:      2, Died:       2, Ages (new/young/stable/ancient): 0/6/0/0\n",
+            "Population is a still life since generation 1.\n",
+            "Elapsed time: 0.000 seconds\n");
+
+        assert_that!(termination, is(equal_to(Ok(Termination::StillLife { generation: 1 }))));
+        assert_that!(out, is(equal_to(String::from(expected))));
+        assert_that!(clock.now(), is(equal_to(Duration::from_millis(0))));
+    }
+
+    #[test]
+    fn run_game_with_dumps_final_generation_if_dump_generation_is_not_reached() {
+        let file = std::env::temp_dir().join("game_of_life_dump_generation_not_reached.rle");
+        let (termination, out, _) = run_test_game(vec![
+            "test", "--width", "4", "--height", "3", "--ratio", "2", "--seed", "7", "--headless",
+            "--dump", file.to_str().unwrap(), "--dump-generation", "50"]);
+        let expected = format!(
+            "Generation 50 to dump was never reached.\nSaved generation 2 to '{}'.\n",
+            file.to_str().unwrap());
+
+        assert_that!(termination, is(equal_to(Ok(Termination::StillLife { generation: 1 }))));
+        assert_that!(out.starts_with(&expected), is(true));
+        assert_that!(pattern::load(file.to_str().unwrap()).is_ok(), is(true));
+
+        fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn remaining_frame_time_subtracts_elapsed_time() {
+        assert_that!(
+            remaining_frame_time(Duration::from_millis(100), Duration::from_millis(30)),
+            is(equal_to(Duration::from_millis(70))));
+    }
+
+    #[test]
+    fn remaining_frame_time_is_zero_if_frame_took_too_long() {
+        assert_that!(
+            remaining_frame_time(Duration::from_millis(100), Duration::from_millis(130)),
+            is(equal_to(Duration::from_millis(0))));
+    }
+
+    #[test]
+    fn create_seeded_rng_with_zero_seed() {
+        assert_that!(create_seeded_rng(0).next_u32() == create_seeded_rng(0).next_u32(), is(true));
+    }
 }
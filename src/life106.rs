@@ -0,0 +1,112 @@
+//! Support for the [Life 1.06][life106] pattern file format.
+//!
+//! ```text
+//! #Life 1.06
+//! 0 -1
+//! 1 0
+//! -1 1
+//! 0 1
+//! 1 1
+//! ```
+//!
+//! [life106]: http://www.conwaylife.com/wiki/Life_1.06
+use crate::pattern::Pattern;
+use crate::place::Place;
+
+/// The header line every file in this format starts with.
+pub const HEADER: &str = "#Life 1.06";
+
+/// Parses a pattern in Life 1.06 format.
+///
+/// The coordinates may be negative, so they are moved to start at zero. Comment lines are ignored
+/// because the format does not define any.
+pub fn parse(input: &str) -> Result<Pattern, String> {
+    let mut lines = input.lines().enumerate().filter(|&(_, line)| !line.trim().is_empty());
+
+    match lines.next() {
+        Some((_, line)) if line.trim() == HEADER => {},
+        _ => return Err(format!("Missing header line '{}'!", HEADER)),
+    }
+
+    let mut coordinates: Vec<(isize, isize)> = Vec::new();
+
+    for (index, line) in lines {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let numbers: Vec<&str> = line.split_whitespace().collect();
+
+        match (numbers.len(), numbers.first().map(|x| x.parse()), numbers.get(1).map(|y| y.parse())) {
+            (2, Some(Ok(x)), Some(Ok(y))) => coordinates.push((x, y)),
+            _ => return Err(format!("Line {}: Two numbers 'x y' expected!", index + 1)),
+        }
+    }
+
+    let min_x = coordinates.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let min_y = coordinates.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let mut cells: Vec<Place> = coordinates.iter()
+        .map(|&(x, y)| Place::new((x - min_x) as usize, (y - min_y) as usize))
+        .collect();
+    cells.sort_by_key(|place| (place.get_y(), place.get_x()));
+    cells.dedup();
+
+    let width = cells.iter().map(|place| place.get_x() + 1).max().unwrap_or(0);
+    let height = cells.iter().map(|place| place.get_y() + 1).max().unwrap_or(0);
+
+    Pattern::new(width, height, cells)
+}
+
+/// Writes a pattern in Life 1.06 format.
+///
+/// The format has no place for meta data, so only the cells are written.
+pub fn write(pattern: &Pattern) -> String {
+    let mut output = String::from(HEADER);
+    output.push('\n');
+
+    for place in pattern.get_cells() {
+        output.push_str(&format!("{} {}\n", place.get_x(), place.get_y()));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    fn glider() -> Pattern {
+        Pattern::new(
+            3,
+            3,
+            vec![Place::new(1, 0), Place::new(2, 1), Place::new(0, 2), Place::new(1, 2), Place::new(2, 2)]).unwrap()
+    }
+
+    #[test]
+    fn parse_glider_with_negative_coordinates() {
+        assert_that!(parse("#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n"), is(equal_to(Ok(glider()))));
+    }
+
+    #[test]
+    fn parse_without_header() {
+        assert_that!(parse("0 0\n"), is(equal_to(Err(String::from("Missing header line '#Life 1.06'!")))));
+    }
+
+    #[test]
+    fn parse_with_bad_coordinates() {
+        assert_that!(
+            parse("#Life 1.06\n0 0\n1 a\n"),
+            is(equal_to(Err(String::from("Line 3: Two numbers 'x y' expected!")))));
+    }
+
+    #[test]
+    fn write_glider() {
+        assert_that!(write(&glider()), is(equal_to(String::from("#Life 1.06\n1 0\n2 1\n0 2\n1 2\n2 2\n"))));
+    }
+
+    #[test]
+    fn write_and_parse_glider() {
+        assert_that!(parse(&write(&glider())), is(equal_to(Ok(glider()))));
+    }
+}
@@ -37,9 +37,17 @@ fn main() {
             .value_name("SLEEP")
             .help(
                 &format!(
-                    "Sets sleep time in seconds between the population iterations. Default is {}.",
+                    "Sets the duration of one population iteration in seconds or with unit (e.g. 250ms). Default is {}.",
                     config::DEFAULT_SLEEP))
             .takes_value(true))
+        .arg(Arg::with_name("fps")
+            .long("fps")
+            .value_name("FPS")
+            .help(
+                &format!(
+                    "Sets the population iterations per second (1 to {}) instead of the sleep time.",
+                    config::MAX_FPS))
+            .takes_value(true))
         .arg(Arg::with_name("ratio")
             .long("ratio")
             .value_name("RATIO")
@@ -48,13 +56,224 @@ fn main() {
                     "A probability ratio used for the initial cell generation. Default is {}.",
                     config::DEFAULT_RATIO))
             .takes_value(true))
+        .arg(Arg::with_name("seed")
+            .long("seed")
+            .value_name("SEED")
+            .help("Sets the seed for the initial cell generation to recreate a population. Default is a random seed.")
+            .takes_value(true))
+        .arg(Arg::with_name("rule")
+            .long("rule")
+            .value_name("RULE")
+            .help(
+                &format!(
+                    "Sets the birth/survival rule in B.../S... or S.../B... notation. Default is {}.",
+                    config::DEFAULT_RULE))
+            .takes_value(true))
+        .arg(Arg::with_name("topology")
+            .long("topology")
+            .value_name("TOPOLOGY")
+            .possible_values(&["plane", "torus"])
+            .help(
+                &format!(
+                    "Sets whether the population space has hard edges (plane) or wraps around (torus). Default is {}.",
+                    config::DEFAULT_TOPOLOGY))
+            .takes_value(true))
+        .arg(Arg::with_name("history")
+            .long("history")
+            .value_name("GENERATIONS")
+            .help(
+                &format!(
+                    "Sets how many generations are remembered to detect still lifes and oscillators. Default is {}.",
+                    config::DEFAULT_HISTORY))
+            .takes_value(true))
+        .arg(Arg::with_name("generations")
+            .long("generations")
+            .value_name("GENERATIONS")
+            .help("Stops the game after the given number of generations. Default is no limit.")
+            .takes_value(true))
+        .arg(Arg::with_name("headless")
+            .long("headless")
+            .help("Runs as fast as possible without printing the generations, only a summary at the end."))
+        .arg(Arg::with_name("interactive")
+            .long("interactive")
+            .help("Controls the running game with the keyboard: [space] pause/resume, [n] next generation, [+/-] speed, [r] restart (pattern, drawing or new random population), [q] quit."))
+        .arg(Arg::with_name("edit")
+            .long("edit")
+            .help(
+                &format!(
+                    "Draws the initial population by hand before the game starts. It is saved to the dump file or {}.",
+                    config::DEFAULT_DRAWING)))
+        .arg(Arg::with_name("zoom")
+            .long("zoom")
+            .value_name("PLACES")
+            .help(
+                &format!(
+                    "Shows squares of the given number of places per character to fit large populations. Default is {}.",
+                    config::DEFAULT_ZOOM))
+            .takes_value(true))
+        .arg(Arg::with_name("render")
+            .long("render")
+            .value_name("MODE")
+            .possible_values(&["cells", "half-blocks", "braille"])
+            .help(
+                &format!(
+                    "Sets how many places are packed into one character: one (cells), 1x2 (half-blocks) or 2x4 (braille). Default is {}.",
+                    config::DEFAULT_RENDER))
+            .takes_value(true))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("MODE")
+            .possible_values(&["off", "256", "truecolor"])
+            .help(
+                &format!(
+                    "Colours living cells by age (newborn, young, stable, ancient) with 256 colours or true colours. Default is {}.",
+                    config::DEFAULT_COLOR))
+            .takes_value(true))
+        .arg(Arg::with_name("trail")
+            .long("trail")
+            .value_name("GENERATIONS")
+            .help(
+                &format!(
+                    "Shows dead cells fading out for the given number of generations, 0 turns trails off. Default is {}.",
+                    config::DEFAULT_TRAIL))
+            .takes_value(true))
+        .arg(Arg::with_name("theme")
+            .long("theme")
+            .value_name("THEME")
+            .possible_values(&["sun", "ascii", "block", "emoji"])
+            .help(
+                &format!(
+                    "Glyphs cells are drawn with: suns, plain ASCII, block elements or emoji. Default is {}.",
+                    config::DEFAULT_THEME))
+            .takes_value(true))
+        .arg(Arg::with_name("glyphs")
+            .long("glyphs")
+            .value_name("ALIVE,DYING,EMPTY")
+            .help("Draws cells with custom glyphs for living cells, dying cells and empty places, e.g. '#,.,-'.")
+            .takes_value(true))
+        .arg(Arg::with_name("no-border")
+            .long("no-border")
+            .help("Omits the frame drawn around the population."))
+        .arg(Arg::with_name("follow")
+            .long("follow")
+            .help("Centers the shown window on the living cells if the population is larger than the terminal."))
+        .arg(Arg::with_name("flicker-free")
+            .long("flicker-free")
+            .help("Draws onto the alternate screen and only rewrites changed cells instead of the whole screen. Stops on [q] or Ctrl-C."))
+        .arg(Arg::with_name("pattern")
+            .long("pattern")
+            .value_name("FILE")
+            .help("Loads the initial population from a pattern file (RLE, Plaintext or Life 1.06) instead of generating it randomly.")
+            .takes_value(true))
+        .arg(Arg::with_name("dump")
+            .long("dump")
+            .value_name("FILE")
+            .help("Saves the final generation to a pattern file. The format is chosen by extension (.rle, .cells, .lif or .svg for a drawing), default is RLE.")
+            .takes_value(true))
+        .arg(Arg::with_name("dump-generation")
+            .long("dump-generation")
+            .value_name("GENERATION")
+            .help("Saves the given generation instead of the final one to the dump file, if it is reached.")
+            .requires("dump")
+            .takes_value(true))
+        .arg(Arg::with_name("snapshot-every")
+            .long("snapshot-every")
+            .value_name("GENERATIONS")
+            .help("Saves every given generation as image into the snapshot directory.")
+            .takes_value(true))
+        .arg(Arg::with_name("snapshot-dir")
+            .long("snapshot-dir")
+            .value_name("DIR")
+            .help(
+                &format!(
+                    "Directory the snapshots are saved to, it is created if missing. Default is '{}'.",
+                    config::DEFAULT_SNAPSHOT_DIR))
+            .requires("snapshot-every")
+            .takes_value(true))
+        .arg(Arg::with_name("snapshot-format")
+            .long("snapshot-format")
+            .value_name("FORMAT")
+            .possible_values(&["png", "pgm", "pbm"])
+            .help(
+                &format!(
+                    "Image format of the snapshots. Default is {}.",
+                    config::DEFAULT_SNAPSHOT_FORMAT))
+            .requires("snapshot-every")
+            .takes_value(true))
+        .arg(Arg::with_name("snapshot-scale")
+            .long("snapshot-scale")
+            .value_name("PIXELS")
+            .help(
+                &format!(
+                    "Number of pixels per place in each direction of the snapshots. Default is {}.",
+                    config::DEFAULT_SNAPSHOT_SCALE))
+            .requires("snapshot-every")
+            .takes_value(true))
+        .arg(Arg::with_name("snapshot-grid")
+            .long("snapshot-grid")
+            .help("Separates the places of the snapshots by grid lines.")
+            .requires("snapshot-every"))
+        .arg(Arg::with_name("record")
+            .long("record")
+            .value_name("FILE")
+            .help("Records the generations into an animated GIF, each shown for the sleep duration.")
+            .takes_value(true))
+        .arg(Arg::with_name("record-from")
+            .long("record-from")
+            .value_name("GENERATION")
+            .help("First generation recorded. Default is 0.")
+            .requires("record")
+            .takes_value(true))
+        .arg(Arg::with_name("record-to")
+            .long("record-to")
+            .value_name("GENERATION")
+            .help("Last generation recorded. Default is the final one.")
+            .requires("record")
+            .takes_value(true))
+        .arg(Arg::with_name("record-frames")
+            .long("record-frames")
+            .value_name("FRAMES")
+            .help(
+                &format!(
+                    "Maximum number of frames recorded, later generations are dropped. Default is {}.",
+                    config::DEFAULT_RECORD_FRAMES))
+            .requires("record")
+            .takes_value(true))
+        .arg(Arg::with_name("record-scale")
+            .long("record-scale")
+            .value_name("PIXELS")
+            .help(
+                &format!(
+                    "Number of pixels per place in each direction of the recording. Default is {}.",
+                    config::DEFAULT_RECORD_SCALE))
+            .requires("record")
+            .takes_value(true))
+        .arg(Arg::with_name("record-loop")
+            .long("record-loop")
+            .help("Lets the recorded animation start over after the last frame.")
+            .requires("record"))
         .get_matches();
 
-    let config = create_config(&matches).unwrap_or_else(|err| {
-        println!("{}", err);
-        process::exit(1);
-    });
+    let config = create_config(&matches).unwrap_or_else(|err| exit_with_error(&err));
+    let termination = run_game(config).unwrap_or_else(|err| exit_with_error(&err));
+
+    process::exit(termination.get_exit_code());
+}
 
-    run_game(config);
+/// Prints the given error and exits with a code telling what went wrong.
+///
+/// The codes follow the BSD `sysexits.h` convention, so they do not clash with the codes of the
+/// termination reasons.
+fn exit_with_error(err: &Error) -> ! {
+    println!("{}", err);
+
+    process::exit(match *err {
+        // EX_USAGE
+        Error::Option { .. } => 64,
+        // EX_DATAERR
+        Error::Pattern { .. } => 65,
+        // EX_IOERR
+        Error::Io { .. } => 74,
+    });
 }
 
@@ -0,0 +1,358 @@
+use std::fs;
+use std::path::Path;
+use crate::cell::Cell;
+use crate::error::Error;
+use crate::life106;
+use crate::place::Place;
+use crate::plaintext;
+use crate::population::Population;
+use crate::rle;
+use crate::rule::Rule;
+
+/// This struct describes a pattern of living cells loaded from a file.
+///
+/// The places of the cells are relative to the upper left corner of the pattern's bounding box.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pattern {
+    /// Optional name of the pattern.
+    name: Option<String>,
+    /// Optional author of the pattern.
+    author: Option<String>,
+    /// Free text comments of the pattern.
+    comments: Vec<String>,
+    /// Width of the pattern.
+    width: usize,
+    /// Height of the pattern.
+    height: usize,
+    /// The rule the pattern is meant for, if given.
+    rule: Option<Rule>,
+    /// The places of the living cells.
+    cells: Vec<Place>,
+}
+
+impl Pattern {
+    /// Create a new pattern without meta data.
+    ///
+    /// Fails if a cell lies outside of the given size.
+    pub fn new(width: usize, height: usize, cells: Vec<Place>) -> Result<Pattern, String> {
+        match cells.iter().find(|place| place.get_x() >= width || place.get_y() >= height) {
+            Some(place) => Err(format!(
+                "Cell at ({}, {}) lies outside of the pattern size {}x{}!",
+                place.get_x(), place.get_y(), width, height)),
+            None => Ok(Pattern::new_unchecked(width, height, cells)),
+        }
+    }
+
+    /// The caller has to make sure that all cells lie within the given size.
+    fn new_unchecked(width: usize, height: usize, cells: Vec<Place>) -> Pattern {
+        Pattern {
+            name: None,
+            author: None,
+            comments: Vec::new(),
+            width,
+            height,
+            rule: None,
+            cells,
+        }
+    }
+
+    /// Creates a copy of this pattern with the given name.
+    pub fn with_name(self, name: String) -> Pattern {
+        Pattern { name: Some(name), ..self }
+    }
+
+    /// Creates a copy of this pattern with the given author.
+    pub fn with_author(self, author: String) -> Pattern {
+        Pattern { author: Some(author), ..self }
+    }
+
+    /// Creates a copy of this pattern with the given comments.
+    pub fn with_comments(self, comments: Vec<String>) -> Pattern {
+        Pattern { comments, ..self }
+    }
+
+    /// Creates a copy of this pattern with the given rule.
+    pub fn with_rule(self, rule: Rule) -> Pattern {
+        Pattern { rule: Some(rule), ..self }
+    }
+
+    /// Creates a pattern from the living cells of the given population.
+    ///
+    /// The pattern is cropped to the bounding box of the living cells.
+    pub fn from_population(population: &Population) -> Pattern {
+        let places = population.get_living_places();
+        let min_x = places.iter().map(|place| place.get_x()).min().unwrap_or(0);
+        let min_y = places.iter().map(|place| place.get_y()).min().unwrap_or(0);
+        let max_x = places.iter().map(|place| place.get_x() + 1).max().unwrap_or(0);
+        let max_y = places.iter().map(|place| place.get_y() + 1).max().unwrap_or(0);
+        let cells: Vec<Place> = places.iter()
+            .map(|place| Place::new(place.get_x() - min_x, place.get_y() - min_y))
+            .collect();
+
+        Pattern::new_unchecked(max_x - min_x, max_y - min_y, cells)
+    }
+
+    /// Get the name of the pattern.
+    pub fn get_name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    /// Get the author of the pattern.
+    pub fn get_author(&self) -> Option<&String> {
+        self.author.as_ref()
+    }
+
+    /// Get the comments of the pattern.
+    pub fn get_comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    /// Get the width of the pattern.
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height of the pattern.
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the rule of the pattern.
+    pub fn get_rule(&self) -> Option<&Rule> {
+        self.rule.as_ref()
+    }
+
+    /// Get the places of the living cells.
+    pub fn get_cells(&self) -> &[Place] {
+        &self.cells
+    }
+
+    /// Creates a population of the given size with this pattern placed in its center.
+    ///
+    /// Cells which do not fit into the population are cut off.
+    pub fn to_population(&self, width: usize, height: usize) -> Population {
+        let offset_x = width.saturating_sub(self.width) / 2;
+        let offset_y = height.saturating_sub(self.height) / 2;
+        let cells: Vec<Cell> = self.cells.iter()
+            .map(|place| Place::new(place.get_x() + offset_x, place.get_y() + offset_y))
+            .filter(|place| place.get_x() < width && place.get_y() < height)
+            .map(Cell::new)
+            .collect();
+
+        Population::new_unchecked(width, height, cells)
+    }
+}
+
+/// The supported pattern file formats.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Format {
+    /// Run Length Encoded (`.rle`).
+    Rle,
+    /// Plaintext (`.cells`).
+    Plaintext,
+    /// Life 1.06 coordinate list (`.lif`, `.life`).
+    Life106,
+}
+
+impl Format {
+    /// Detects the format from the extension of the given file name.
+    pub fn from_extension(file: &str) -> Option<Format> {
+        let extension = Path::new(file).extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "rle" => Some(Format::Rle),
+            "cells" => Some(Format::Plaintext),
+            "lif" | "life" => Some(Format::Life106),
+            _ => None,
+        }
+    }
+
+    /// Detects the format by looking at the first meaningful line of the content.
+    ///
+    /// RLE is assumed if the content does not look like any other format.
+    pub fn sniff(content: &str) -> Format {
+        let first = content.lines().map(|line| line.trim()).find(|line| !line.is_empty());
+
+        match first {
+            Some(line) if line.starts_with(life106::HEADER) => Format::Life106,
+            Some(line) if line.starts_with('!') => Format::Plaintext,
+            Some(line) if line.chars().all(|c| c == '.' || c == 'O' || c == '*') => Format::Plaintext,
+            _ => Format::Rle,
+        }
+    }
+
+    /// Parses a pattern in this format.
+    pub fn parse(&self, content: &str) -> Result<Pattern, String> {
+        match *self {
+            Format::Rle => rle::parse(content),
+            Format::Plaintext => plaintext::parse(content),
+            Format::Life106 => life106::parse(content),
+        }
+    }
+
+    /// Writes a pattern in this format.
+    pub fn write(&self, pattern: &Pattern) -> String {
+        match *self {
+            Format::Rle => rle::write(pattern),
+            Format::Plaintext => plaintext::write(pattern),
+            Format::Life106 => life106::write(pattern),
+        }
+    }
+}
+
+/// Parses a pattern from the given content, whose format is detected by looking at it.
+pub fn parse(content: &str) -> Result<Pattern, Error> {
+    Format::sniff(content).parse(content).map_err(|reason| Error::pattern(None, reason))
+}
+
+/// Loads a pattern from the given file.
+///
+/// The format is detected by the file extension, or by the content if the extension is unknown.
+pub fn load(file: &str) -> Result<Pattern, Error> {
+    let content = fs::read_to_string(file)
+        .map_err(|err| Error::io(format!("read pattern file '{}'", file), err))?;
+    let format = Format::from_extension(file).unwrap_or_else(|| Format::sniff(&content));
+
+    format.parse(&content).map_err(|reason| Error::pattern(Some(file), reason))
+}
+
+/// Saves a pattern to the given file.
+///
+/// The format is chosen by the file extension, RLE is used if the extension is unknown.
+pub fn save(file: &str, pattern: &Pattern) -> Result<(), Error> {
+    let format = Format::from_extension(file).unwrap_or(Format::Rle);
+
+    fs::write(file, format.write(pattern))
+        .map_err(|err| Error::io(format!("write pattern file '{}'", file), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn new_with_cell_outside_of_size() {
+        assert_that!(
+            Pattern::new(3, 1, vec![Place::new(0, 0), Place::new(1, 1)]),
+            is(equal_to(Err(String::from("Cell at (1, 1) lies outside of the pattern size 3x1!")))));
+    }
+
+    #[test]
+    fn to_population_centers_pattern() {
+        let sut = Pattern::new(3, 1, vec![Place::new(0, 0), Place::new(1, 0), Place::new(2, 0)]).unwrap();
+        let expected = r#"+-----+
+|     |
+| ☀☀☀ |
+|     |
++-----+
+"#;
+
+        assert_that!(format!("{}", sut.to_population(5, 3)), is(equal_to(String::from(expected))));
+    }
+
+    #[test]
+    fn to_population_cuts_off_too_large_pattern() {
+        let sut = Pattern::new(3, 1, vec![Place::new(0, 0), Place::new(1, 0), Place::new(2, 0)]).unwrap();
+
+        assert_that!(sut.to_population(2, 1).get_status().get_cells(), is(equal_to(2)));
+    }
+
+    #[test]
+    fn from_population_crops_to_living_cells() {
+        let population = Population::new(
+            10,
+            10,
+            vec![Cell::new(Place::new(3, 2)), Cell::new(Place::new(5, 4)), Cell::new(Place::new(0, 0)).kill()]).unwrap();
+
+        assert_that!(
+            Pattern::from_population(&population),
+            is(equal_to(Pattern::new(3, 3, vec![Place::new(0, 0), Place::new(2, 2)]).unwrap())));
+    }
+
+    #[test]
+    fn from_empty_population() {
+        let population = Population::new(10, 10, Vec::new()).unwrap();
+
+        assert_that!(Pattern::from_population(&population), is(equal_to(Pattern::new(0, 0, Vec::new()).unwrap())));
+    }
+
+    #[test]
+    fn save_and_load_pattern() {
+        let file = std::env::temp_dir().join("game_of_life_save_and_load_pattern.rle");
+        let file = file.to_str().unwrap();
+        let pattern = Pattern::new(3, 1, vec![Place::new(0, 0), Place::new(2, 0)]).unwrap()
+            .with_name(String::from("Foo"))
+            .with_rule(Rule::conway());
+
+        assert_that!(save(file, &pattern), is(equal_to(Ok(()))));
+        assert_that!(load(file), is(equal_to(Ok(pattern))));
+    }
+
+    #[test]
+    fn save_and_load_pattern_as_plaintext() {
+        let file = std::env::temp_dir().join("game_of_life_save_and_load_pattern.cells");
+        let file = file.to_str().unwrap();
+        let pattern = Pattern::new(3, 2, vec![Place::new(0, 0), Place::new(2, 1)]).unwrap();
+
+        assert_that!(save(file, &pattern), is(equal_to(Ok(()))));
+        assert_that!(fs::read_to_string(file).unwrap(), is(equal_to(String::from("O..\n..O\n"))));
+        assert_that!(load(file), is(equal_to(Ok(pattern))));
+    }
+
+    #[test]
+    fn load_pattern_with_unknown_extension_by_content() {
+        let file = std::env::temp_dir().join("game_of_life_load_pattern_by_content.txt");
+        let file = file.to_str().unwrap();
+        fs::write(file, "#Life 1.06\n0 0\n1 1\n").unwrap();
+
+        assert_that!(load(file), is(equal_to(Ok(Pattern::new(2, 2, vec![Place::new(0, 0), Place::new(1, 1)]).unwrap()))));
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_that!(Format::from_extension("foo.rle"), is(equal_to(Some(Format::Rle))));
+        assert_that!(Format::from_extension("foo.RLE"), is(equal_to(Some(Format::Rle))));
+        assert_that!(Format::from_extension("foo.cells"), is(equal_to(Some(Format::Plaintext))));
+        assert_that!(Format::from_extension("foo.lif"), is(equal_to(Some(Format::Life106))));
+        assert_that!(Format::from_extension("foo.life"), is(equal_to(Some(Format::Life106))));
+        assert_that!(Format::from_extension("foo.txt"), is(equal_to(None)));
+        assert_that!(Format::from_extension("foo"), is(equal_to(None)));
+    }
+
+    #[test]
+    fn format_sniff() {
+        assert_that!(Format::sniff("\n#Life 1.06\n0 0\n"), is(equal_to(Format::Life106)));
+        assert_that!(Format::sniff("!Name: Glider\n.O\n"), is(equal_to(Format::Plaintext)));
+        assert_that!(Format::sniff(".O.\n..O\n"), is(equal_to(Format::Plaintext)));
+        assert_that!(Format::sniff("#N Glider\nx = 3, y = 3\n"), is(equal_to(Format::Rle)));
+        assert_that!(Format::sniff("x = 3, y = 3\n"), is(equal_to(Format::Rle)));
+    }
+
+    #[test]
+    fn parse_detects_format() {
+        let sut = parse("#Life 1.06\n0 0\n1 0\n").unwrap();
+
+        assert_that!(sut.get_cells(), is(equal_to(&[Place::new(0, 0), Place::new(1, 0)][..])));
+    }
+
+    #[test]
+    fn load_not_existing_file() {
+        let result = load("/not/existing/pattern.rle");
+
+        match result {
+            Err(Error::Io { action, source }) => {
+                assert_that!(action, is(equal_to(String::from("read pattern file '/not/existing/pattern.rle'"))));
+                assert_that!(source.kind(), is(equal_to(std::io::ErrorKind::NotFound)));
+            },
+            _ => panic!("Expected I/O error as result!"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_pattern() {
+        assert_that!(
+            parse("x = 1\no!"),
+            is(equal_to(Err(Error::pattern(None, String::from("Line 1: Header must contain 'x' and 'y'!"))))));
+    }
+}
@@ -0,0 +1,201 @@
+//! Support for the [Plaintext][plaintext] (`.cells`) pattern file format.
+//!
+//! ```text
+//! !Name: Glider
+//! !A small spaceship.
+//! .O.
+//! ..O
+//! OOO
+//! ```
+//!
+//! [plaintext]: http://www.conwaylife.com/wiki/Plaintext
+use crate::pattern::Pattern;
+use crate::place::Place;
+use crate::rule::Rule;
+
+/// Parses a pattern in Plaintext format.
+///
+/// The comment lines `!Name:`, `!Author:` and `!Rule:` are used as meta data of the pattern, all
+/// other comment lines are kept as comments. Living cells are marked with `O` (or `*`), dead ones
+/// with `.`.
+pub fn parse(input: &str) -> Result<Pattern, String> {
+    let mut name = None;
+    let mut author = None;
+    let mut rule = None;
+    let mut comments: Vec<String> = Vec::new();
+    // The rows of the pattern along with their line number.
+    let mut rows: Vec<(usize, &str)> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+
+        if line.starts_with('!') && rows.is_empty() {
+            let text = line[1..].trim();
+
+            if let Some(value) = text.strip_prefix("Name:") {
+                name = Some(value.trim().to_string());
+            } else if let Some(value) = text.strip_prefix("Author:") {
+                author = Some(value.trim().to_string());
+            } else if let Some(value) = text.strip_prefix("Rule:") {
+                let parsed = Rule::parse(value).map_err(|reason| format!("Line {}: {}", index + 1, reason))?;
+                rule = Some(parsed);
+            } else {
+                comments.push(text.to_string());
+            }
+        } else {
+            rows.push((index + 1, line));
+        }
+    }
+
+    // Trailing empty lines are no rows of the pattern.
+    while rows.last().is_some_and(|&(_, row)| row.is_empty()) {
+        rows.pop();
+    }
+
+    let mut cells: Vec<Place> = Vec::new();
+    let mut width = 0;
+
+    for (y, &(line_number, row)) in rows.iter().enumerate() {
+        for (x, tag) in row.chars().enumerate() {
+            match tag {
+                'O' | '*' => cells.push(Place::new(x, y)),
+                '.' => {},
+                _ => return Err(format!("Line {}: Invalid character '{}' in pattern!", line_number, tag)),
+            }
+
+            width = width.max(x + 1);
+        }
+    }
+
+    let mut pattern = Pattern::new(width, rows.len(), cells)?.with_comments(comments);
+
+    if let Some(name) = name {
+        pattern = pattern.with_name(name);
+    }
+
+    if let Some(author) = author {
+        pattern = pattern.with_author(author);
+    }
+
+    if let Some(rule) = rule {
+        pattern = pattern.with_rule(rule);
+    }
+
+    Ok(pattern)
+}
+
+/// Writes a pattern in Plaintext format.
+///
+/// The name, author and rule of the pattern are written as comment lines in the common
+/// `!Key: value` form.
+pub fn write(pattern: &Pattern) -> String {
+    let mut output = String::new();
+
+    if let Some(name) = pattern.get_name() {
+        output.push_str(&format!("!Name: {}\n", name));
+    }
+
+    if let Some(author) = pattern.get_author() {
+        output.push_str(&format!("!Author: {}\n", author));
+    }
+
+    if let Some(rule) = pattern.get_rule() {
+        output.push_str(&format!("!Rule: {}\n", rule));
+    }
+
+    for comment in pattern.get_comments() {
+        output.push_str(&format!("!{}\n", comment));
+    }
+
+    let mut rows = vec![vec!['.'; pattern.get_width()]; pattern.get_height()];
+
+    for place in pattern.get_cells() {
+        rows[place.get_y()][place.get_x()] = 'O';
+    }
+
+    for row in rows {
+        output.extend(row);
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    fn glider() -> Pattern {
+        Pattern::new(
+            3,
+            3,
+            vec![Place::new(1, 0), Place::new(2, 1), Place::new(0, 2), Place::new(1, 2), Place::new(2, 2)]).unwrap()
+    }
+
+    #[test]
+    fn parse_glider() {
+        let input = r#"!Name: Glider
+!The smallest, most common, and first discovered spaceship.
+!
+.O
+..O
+OOO
+"#;
+        let expected = glider()
+            .with_name(String::from("Glider"))
+            .with_comments(vec![
+                String::from("The smallest, most common, and first discovered spaceship."),
+                String::new()]);
+
+        assert_that!(parse(input), is(equal_to(Ok(expected))));
+    }
+
+    #[test]
+    fn parse_with_empty_rows() {
+        let expected = Pattern::new(2, 3, vec![Place::new(0, 0), Place::new(1, 2)]).unwrap();
+
+        assert_that!(parse("O\n\n.*\n\n"), is(equal_to(Ok(expected))));
+    }
+
+    #[test]
+    fn parse_with_invalid_character() {
+        assert_that!(
+            parse("!Foo\n.O\nOx"),
+            is(equal_to(Err(String::from("Line 3: Invalid character 'x' in pattern!")))));
+    }
+
+    #[test]
+    fn parse_with_bad_rule() {
+        assert_that!(
+            parse("!Name: Foo\n!Rule: X3/23\nO"),
+            is(equal_to(Err(String::from("Line 2: Rule part 'X3' must start with B or S!")))));
+    }
+
+    #[test]
+    fn write_glider() {
+        let pattern = glider()
+            .with_name(String::from("Glider"))
+            .with_comments(vec![String::from("A small spaceship.")]);
+        let expected = r#"!Name: Glider
+!A small spaceship.
+.O.
+..O
+OOO
+"#;
+
+        assert_that!(write(&pattern), is(equal_to(String::from(expected))));
+    }
+
+    #[test]
+    fn write_and_parse_glider() {
+        let pattern = glider()
+            .with_name(String::from("Glider"))
+            .with_author(String::from("Richard K. Guy"))
+            .with_rule(Rule::conway())
+            .with_comments(vec![String::from("A small spaceship.")]);
+        let written = write(&pattern);
+
+        assert_that!(parse(&written), is(equal_to(Ok(pattern))));
+    }
+}
@@ -0,0 +1,144 @@
+//! A minimal encoder for the [Portable Network Graphics][png] image format.
+//!
+//! The image data is written in stored, i.e. uncompressed, deflate blocks. This needs no
+//! compression library and is readable by any PNG decoder, but the files are about as large as
+//! the raw pixels: three bytes per pixel. Recompress them with a tool like `optipng` if size
+//! matters.
+//!
+//! [png]: https://www.w3.org/TR/png/
+
+use crate::image::Color;
+
+/// The eight bytes every PNG file starts with.
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// Bit depth of each colour channel.
+const BIT_DEPTH: u8 = 8;
+/// Colour type of pixels given as red, green and blue.
+const COLOR_TYPE_RGB: u8 = 2;
+/// Filter type of scanlines which are stored as they are.
+const FILTER_NONE: u8 = 0;
+/// Maximum number of bytes of an uncompressed deflate block.
+const MAX_STORED_BLOCK: usize = 0xffff;
+/// Modulus of the Adler-32 checksum.
+const ADLER_MODULUS: u32 = 65521;
+
+/// Encodes the given pixels, row by row, as PNG.
+pub fn encode(width: usize, height: usize, pixels: &[Color]) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // Bit depth, colour type, compression, filter and interlace method.
+    header.extend_from_slice(&[BIT_DEPTH, COLOR_TYPE_RGB, 0, 0, 0]);
+
+    let mut scanlines = Vec::with_capacity(height * (width * 3 + 1));
+
+    for row in pixels.chunks(width.max(1)).take(height) {
+        scanlines.push(FILTER_NONE);
+
+        for &(red, green, blue) in row {
+            scanlines.extend_from_slice(&[red, green, blue]);
+        }
+    }
+
+    let mut png = SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Appends a chunk with its length and checksum.
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let mut checked = kind.to_vec();
+    checked.extend_from_slice(data);
+    png.extend_from_slice(&crc32(&checked).to_be_bytes());
+}
+
+/// Wraps the given data into a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and no preset dictionary, the check bits make it divisible by 31.
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() { vec![data] } else { data.chunks(MAX_STORED_BLOCK).collect() };
+
+    for (index, block) in blocks.iter().enumerate() {
+        let is_final = index + 1 == blocks.len();
+        let length = block.len() as u16;
+
+        stream.push(if is_final { 1 } else { 0 });
+        stream.extend_from_slice(&length.to_le_bytes());
+        stream.extend_from_slice(&(!length).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+/// The CRC-32 checksum PNG chunks end with.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+
+    for &byte in data {
+        crc ^= u32::from(byte);
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+/// The Adler-32 checksum zlib streams end with.
+fn adler32(data: &[u8]) -> u32 {
+    let mut low = 1_u32;
+    let mut high = 0_u32;
+
+    for &byte in data {
+        low = (low + u32::from(byte)) % ADLER_MODULUS;
+        high = (high + low) % ADLER_MODULUS;
+    }
+
+    (high << 16) | low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn crc32_of_chunk_type() {
+        assert_that!(crc32(b"IEND"), is(equal_to(0xae42_6082)));
+    }
+
+    #[test]
+    fn adler32_of_text() {
+        assert_that!(adler32(b"Wikipedia"), is(equal_to(0x11e6_0398)));
+    }
+
+    #[test]
+    fn zlib_stored_splits_large_data_into_blocks() {
+        let data = vec![7; MAX_STORED_BLOCK + 1];
+        let stream = zlib_stored(&data);
+
+        // Header, two blocks with five bytes of block header each and the checksum.
+        assert_that!(stream.len(), is(equal_to(2 + 5 + MAX_STORED_BLOCK + 5 + 1 + 4)));
+        assert_that!(stream[2], is(equal_to(0)));
+        assert_that!(stream[2 + 5 + MAX_STORED_BLOCK], is(equal_to(1)));
+    }
+
+    #[test]
+    fn encode_single_pixel() {
+        let png = encode(1, 1, &[(255, 0, 0)]);
+
+        assert_that!(&png[..8], is(equal_to(&SIGNATURE[..])));
+        // Length and type of the header chunk followed by width and height.
+        assert_that!(&png[8..24], is(equal_to(&[0, 0, 0, 13, b'I', b'H', b'D', b'R', 0, 0, 0, 1, 0, 0, 0, 1][..])));
+        assert_that!(&png[png.len() - 12..], is(equal_to(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82][..])));
+    }
+}
@@ -1,140 +1,324 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use crate::age::{AgeClass, AGE_CLASSES};
 use crate::status::Status;
+use crate::theme::Theme;
 use crate::dimension::Dimension;
+use crate::generations::Generations;
 use crate::cell::Cell;
-use crate::place::{Place, distance};
+use crate::place::Place;
+use crate::rule::Rule;
+use crate::topology::Topology;
+
+/// Number of generations dead cells are kept if not configured otherwise.
+const DEFAULT_TRAIL: usize = 1;
 
 /// This struct describes a population of cells.
+///
+/// The cells are stored sparse by their place, so the costs of generating the next generation
+/// scale with the number of living cells and their neighbourhoods instead of the whole space.
 #[derive(PartialEq, Clone)]
 pub struct Population {
     /// The status of this population.
     status: Status,
     /// The dimension of the population.
     size: Dimension,
-    /// The living cells of this population.
-    cells: Vec<Cell>,
+    /// How the edges of the population space are connected.
+    topology: Topology,
+    /// Number of generations dead cells are kept to show the trail of the population.
+    trail: usize,
+    /// How the cells and the frame are drawn.
+    theme: Theme,
+    /// The living (and just died) cells of this population by their place.
+    cells: HashMap<Place, Cell>,
 }
 
 impl Population {
-    /// Create a new population.
-    pub fn new(width: usize, height: usize, cells: Vec<Cell>) -> Population {
+    /// Create a new population on a hard-edged plane.
+    ///
+    /// Fails if a cell lies outside of the given size.
+    pub fn new(width: usize, height: usize, cells: Vec<Cell>) -> Result<Population, String> {
+        let outside = cells.iter()
+            .map(|cell| cell.get_position())
+            .find(|place| place.get_x() >= width || place.get_y() >= height);
+
+        match outside {
+            Some(place) => Err(format!(
+                "Cell at ({}, {}) lies outside of the population size {}x{}!",
+                place.get_x(), place.get_y(), width, height)),
+            None => Ok(Population::new_unchecked(width, height, cells)),
+        }
+    }
+
+    /// The caller has to make sure that all cells lie within the given size.
+    pub(crate) fn new_unchecked(width: usize, height: usize, cells: Vec<Cell>) -> Population {
+        let cells: HashMap<Place, Cell> = cells.into_iter()
+            .map(|cell| (cell.get_position().clone(), cell))
+            .collect();
+        let living = cells.values().filter(|cell| !cell.is_dead()).count();
+
         Population {
-            status: Status::new(0, cells.len(), 0, 0),
+            status: Status::new(0, living, 0, 0).with_ages(count_ages(&cells)),
             size: Dimension::new(width, height),
+            topology: Topology::Plane,
+            trail: DEFAULT_TRAIL,
+            theme: Theme::default(),
             cells,
         }
     }
 
+    /// Creates a copy of this population which lives on the given topology.
+    pub fn with_topology(self, topology: Topology) -> Population {
+        Population { topology, ..self }
+    }
+
+    /// Creates a copy of this population which keeps dead cells for the given number of
+    /// generations. With zero dead cells are dropped at once.
+    pub fn with_trail(self, trail: usize) -> Population {
+        Population { trail, ..self }
+    }
+
+    /// Creates a copy of this population which is drawn with the given theme.
+    pub fn with_theme(self, theme: Theme) -> Population {
+        Population { theme, ..self }
+    }
+
+    /// Creates a copy of this population where the cell at the given place is toggled between
+    /// living and empty. All other cells and the status stay as they are, places outside of the
+    /// population are ignored.
+    pub fn with_toggled_cell(mut self, place: &Place) -> Population {
+        if place.get_x() >= self.size.get_width() || place.get_y() >= self.size.get_height() {
+            return self;
+        }
+
+        let mut ages = *self.status.get_ages();
+        let living = match self.cells.remove(place) {
+            Some(ref cell) if !cell.is_dead() => {
+                ages[AgeClass::of(cell.get_age()).get_index()] -= 1;
+                self.status.get_cells() - 1
+            },
+            _ => {
+                let cell = Cell::new(place.clone());
+                ages[AgeClass::of(cell.get_age()).get_index()] += 1;
+                self.cells.insert(place.clone(), cell);
+                self.status.get_cells() + 1
+            },
+        };
+
+        self.status = Status::new(self.status.get_iteration(), living, self.status.get_born(), self.status.get_died())
+            .with_ages(ages);
+        self
+    }
+
+    /// Creates a copy of this population without any cells.
+    pub fn cleared(&self) -> Population {
+        self.with_living_places(Vec::new())
+    }
+
+    /// Creates a copy of this population with a living cell at every place.
+    pub fn filled(&self) -> Population {
+        let places = self.get_all_places().collect();
+        self.with_living_places(places)
+    }
+
+    /// Creates a copy of this population with living cells exactly at the places which are empty
+    /// in this one.
+    pub fn inverted(&self) -> Population {
+        let places = self.get_all_places()
+            .filter(|place| !self.has_living_cell(place))
+            .collect();
+        self.with_living_places(places)
+    }
+
+    /// The copy starts over at the first iteration, because it is a new population.
+    fn with_living_places(&self, places: Vec<Place>) -> Population {
+        Population::new_unchecked(
+            self.size.get_width(),
+            self.size.get_height(),
+            places.into_iter().map(Cell::new).collect())
+            .with_topology(self.topology.clone())
+            .with_trail(self.trail)
+            .with_theme(self.theme.clone())
+    }
+
+    fn get_all_places(&self) -> impl Iterator<Item = Place> {
+        let width = self.size.get_width();
+        let height = self.size.get_height();
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| Place::new(x, y)))
+    }
+
+    /// Get the number of generations dead cells are kept.
+    pub fn get_trail(&self) -> usize {
+        self.trail
+    }
+
+    /// Get the theme the cells and the frame are drawn with.
+    pub fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Get the glyph of the cell at the given place, or of the empty place.
+    pub fn get_glyph(&self, position: &Place) -> &str {
+        match self.cells.get(position) {
+            Some(cell) => self.theme.get_glyph(cell, self.trail),
+            None => self.theme.get_empty(),
+        }
+    }
+
+    /// Get the dimension of the population space.
+    pub fn get_size(&self) -> &Dimension {
+        &self.size
+    }
+
+    /// Get the status of this generation.
     pub fn get_status(&self) -> Status {
         self.status.clone()
     }
 
-    fn get_cells(&self) -> Vec<Cell> {
-        self.cells.clone()
+    /// Creates a stepper through the following generations of this population under the given
+    /// rule.
+    pub fn generations(&self, rule: &Rule) -> Generations {
+        Generations::new(self.clone(), rule.clone())
     }
 
-    /// Generates the next evolution iteration of this population.
-    pub fn next_generation(&self) -> Population {
-        let (next, survived) = self.visit_all_places();
+    /// Generates the next evolution iteration of this population by applying the given rule.
+    pub fn next_generation(&self, rule: &Rule) -> Population {
+        let (next, survived) = self.visit_living_cells(rule);
 
         Population {
-            status: next,
+            status: next.with_ages(count_ages(&survived)),
             size: self.size.clone(),
+            topology: self.topology.clone(),
+            trail: self.trail,
+            theme: self.theme.clone(),
             cells: survived,
         }
     }
 
-    fn visit_all_places(&self) -> (Status, Vec<Cell>) {
+    /// Only places with at least one living neighbour may change, so only the living cells and
+    /// their neighbourhoods are visited.
+    fn visit_living_cells(&self, rule: &Rule) -> (Status, HashMap<Place, Cell>) {
         let mut next = self.get_status().inc_iteration();
-        let mut survived: Vec<Cell> = Vec::new();
+        let mut survived: HashMap<Place, Cell> = HashMap::with_capacity(self.cells.len());
+        let neighbours = count_neighbours(&self.cells, &self.topology, &self.size);
 
-        for y in 0..self.size.get_height() {
-            for x in 0..self.size.get_width() {
-                next = self.visit_place(Place::new(x, y), next, &mut survived);
+        for cell in self.cells.values() {
+            let number_of_neighbours = neighbours.get(cell.get_position()).cloned().unwrap_or(0);
+            next = self.kill_cell_if_necessary(rule, next, &mut survived, number_of_neighbours, cell);
+        }
+
+        for (place, &number_of_neighbours) in &neighbours {
+            if !self.has_living_cell(place) {
+                next = self.spawn_cell_if_possible(rule, place, next, &mut survived, number_of_neighbours);
             }
         }
 
         (next, survived)
     }
 
-    fn visit_place(&self, current_place: Place, next: Status, survived: &mut Vec<Cell>) -> Status {
-        let number_of_neighbours = count_neighbours(&self.cells, &current_place);
-
-        match self.get_cell(&current_place) {
-            Some(cell) => self.kill_cell_if_necessary(next, survived, number_of_neighbours, cell),
-            None => self.spawn_cell_if_possible(current_place, next, survived, number_of_neighbours),
-        }
-    }
-
-    fn kill_cell_if_necessary(&self, next: Status, survived: &mut Vec<Cell>, number_of_neighbours: usize, cell: Cell) -> Status {
+    fn kill_cell_if_necessary(&self, rule: &Rule, next: Status, survived: &mut HashMap<Place, Cell>, number_of_neighbours: usize, cell: &Cell) -> Status {
         if cell.is_dead() {
-            // Fade out dead cells.
+            // Fade out dead cells until the end of the trail.
+            if cell.get_decay() + 1 < self.trail {
+                survived.insert(cell.get_position().clone(), cell.fade());
+            }
+
             return next;
         }
 
-        return if should_die(number_of_neighbours) {
-            survived.push(cell.kill());
+        if rule.should_die(number_of_neighbours) {
+            if self.trail > 0 {
+                survived.insert(cell.get_position().clone(), cell.kill());
+            }
+
             next.inc_died()
         } else {
-            survived.push(cell);
+            survived.insert(cell.get_position().clone(), cell.grow_older());
             next
         }
     }
 
-    fn spawn_cell_if_possible(&self, current_place: Place, next: Status, survived: &mut Vec<Cell>, number_of_neighbours: usize) -> Status {
-        if should_spawn(number_of_neighbours) {
-            survived.push(Cell::new(current_place));
+    fn spawn_cell_if_possible(&self, rule: &Rule, current_place: &Place, next: Status, survived: &mut HashMap<Place, Cell>, number_of_neighbours: usize) -> Status {
+        if rule.should_spawn(number_of_neighbours) {
+            survived.insert(current_place.clone(), Cell::new(current_place.clone()));
             return next.inc_born();
         }
 
         next
     }
 
-    fn get_cell(&self, position: &Place) -> Option<Cell> {
-        let mut it = self.get_cells().into_iter();
-        it.find(|cell| cell.get_position() == position)
+    /// Get the places of all living cells ordered by row and column.
+    pub fn get_living_places(&self) -> Vec<Place> {
+        let mut places: Vec<Place> = self.cells.values()
+            .filter(|cell| !cell.is_dead())
+            .map(|cell| cell.get_position().clone())
+            .collect();
+        places.sort_by_key(|place| (place.get_y(), place.get_x()));
+        places
     }
 
-    fn has_cell(&self, position: &Place) -> bool {
-        match self.get_cell(position) {
-            Some(_) => true,
+    /// Calculates a hash of the living cells, which is equal for equal generations.
+    ///
+    /// The hash does not depend on the status or the dying cells of the population.
+    pub fn get_state_hash(&self) -> u64 {
+        hash_places(&self.get_living_places())
+    }
+
+    /// Get the living or dying cell at the given place, if any.
+    pub fn get_cell(&self, position: &Place) -> Option<Cell> {
+        self.cells.get(position).cloned()
+    }
+
+    fn has_living_cell(&self, position: &Place) -> bool {
+        match self.cells.get(position) {
+            Some(cell) => !cell.is_dead(),
             None => false,
         }
     }
+
+    #[cfg(test)]
+    fn has_cell(&self, position: &Place) -> bool {
+        self.get_cell(position).is_some()
+    }
 }
 
 impl fmt::Display for Population {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut buf = String::new();
-        buf.push_str(&generate_line_for_population(self.size.get_width()));
-        buf.push('\n');
+
+        if self.theme.has_border() {
+            buf.push_str(&self.theme.generate_line(self.size.get_width()));
+            buf.push('\n');
+        }
 
         for y in 0..self.size.get_height() {
-            buf.push('|');
+            if self.theme.has_border() {
+                buf.push_str(self.theme.get_side());
+            }
 
             for x in 0..self.size.get_width() {
-                let current = Place::new(x, y);
+                buf.push_str(self.get_glyph(&Place::new(x, y)));
+            }
 
-                match self.get_cell(&current) {
-                    Some(cell) => buf.push_str(&format!("{}", cell)),
-                    None => buf.push(' '),
-                }
+            if self.theme.has_border() {
+                buf.push_str(self.theme.get_side());
             }
 
-            buf.push('|');
             buf.push('\n');
         }
 
-        buf.push_str(&generate_line_for_population(self.size.get_width()));
-        buf.push('\n');
+        if self.theme.has_border() {
+            buf.push_str(&self.theme.generate_line(self.size.get_width()));
+            buf.push('\n');
+        }
+
         write!(f, "{}", buf)
     }
 }
 
-fn generate_line_for_population(width: usize) -> String {
-    format!("+{}+", "-".repeat(width))
-}
-
 impl fmt::Debug for Population {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut buf = String::new();
@@ -143,12 +327,7 @@ impl fmt::Debug for Population {
             buf.push_str(&format!("{}", y));
 
             for x in 0..self.size.get_width() {
-                let current = Place::new(x, y);
-
-                match self.get_cell(&current) {
-                    Some(cell) => buf.push_str(&format!("{}", cell)),
-                    None => buf.push(' '),
-                }
+                buf.push_str(self.get_glyph(&Place::new(x, y)));
             }
 
             buf.push('\n');
@@ -157,73 +336,202 @@ impl fmt::Debug for Population {
         buf.push(' ');
 
         for x in 0..self.size.get_width() {
-            buf.push_str(&format!("{}", x));
+            buf.push_str(&format!("{:<width$}", x, width = self.theme.get_width()));
         }
 
         write!(f, "{}", buf)
     }
 }
 
-/// A cell should die if it has less than two or more than three neighbours.
-fn should_die(number_of_neighbours: usize) -> bool {
-    match number_of_neighbours {
-        2 | 3 => false,
-        _ => true,
-    }
+/// This function counts the living cells per age class.
+/// Calculates the state hash of the given places of living cells ordered by row and column.
+pub(crate) fn hash_places(places: &[Place]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    places.hash(&mut hasher);
+    hasher.finish()
 }
 
-/// At an empty place a new cell should spawn, if this cell has exactly three living cells as
-/// neighbour.
-fn should_spawn(number_of_neighbours: usize) -> bool {
-    number_of_neighbours == 3
-}
+fn count_ages(cells: &HashMap<Place, Cell>) -> [usize; AGE_CLASSES] {
+    let mut ages = [0; AGE_CLASSES];
 
-/// This function counts the number of neighbours (living cells) for a given place.
-fn count_neighbours(cells: &Vec<Cell>, position: &Place) -> usize {
-    let mut neighbours = 0;
+    for cell in cells.values().filter(|cell| !cell.is_dead()) {
+        ages[AgeClass::of(cell.get_age()).get_index()] += 1;
+    }
 
-    for cell in cells {
-        if cell.is_dead() {
-            continue;
-        }
+    ages
+}
 
-        let distance = distance(cell.get_position(), position);
+/// This function counts the number of living neighbours for all places next to living cells.
+///
+/// Places which are not part of the result have no living neighbours at all.
+fn count_neighbours(cells: &HashMap<Place, Cell>, topology: &Topology, size: &Dimension) -> HashMap<Place, usize> {
+    let mut neighbours: HashMap<Place, usize> = HashMap::with_capacity(cells.len() * 8);
 
-        if distance > 0.0 && distance < 2.0 {
-            neighbours += 1;
+    for cell in cells.values().filter(|cell| !cell.is_dead()) {
+        for place in topology.neighbours(cell.get_position(), size) {
+            *neighbours.entry(place).or_insert(0) += 1;
         }
     }
 
     neighbours
 }
 
+/// A blinker in the middle row of a 3x3 population, shared by the tests of several modules.
+#[cfg(test)]
+pub(crate) fn create_blinker() -> Population {
+    Population::new(3, 3, vec![
+        Cell::new(Place::new(0, 1)),
+        Cell::new(Place::new(1, 1)),
+        Cell::new(Place::new(2, 1)),
+    ]).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use hamcrest::prelude::*;
 
+    fn count_neighbours_on_plane(cells: Vec<Cell>, position: &Place) -> usize {
+        let cells: HashMap<Place, Cell> = cells.into_iter()
+            .map(|cell| (cell.get_position().clone(), cell))
+            .collect();
+        let neighbours = count_neighbours(&cells, &Topology::Plane, &Dimension::new(10, 10));
+
+        neighbours.get(position).cloned().unwrap_or(0)
+    }
+
+    #[test]
+    fn new_with_cell_outside_of_size() {
+        match Population::new(3, 3, vec![Cell::new(Place::new(0, 0)), Cell::new(Place::new(3, 2))]) {
+            Err(reason) => assert_that!(
+                reason,
+                is(equal_to(String::from("Cell at (3, 2) lies outside of the population size 3x3!")))),
+            Ok(_) => panic!("Expected cell outside of the population to be rejected!"),
+        }
+    }
+
+    #[test]
+    fn with_toggled_cell_adds_and_removes_cell() {
+        let sut = Population::new(3, 3, vec![Cell::new(Place::new(0, 0))]).unwrap();
+
+        let toggled = sut.with_toggled_cell(&Place::new(1, 1)).with_toggled_cell(&Place::new(0, 0));
+
+        assert_that!(toggled.get_living_places(), is(equal_to(vec![Place::new(1, 1)])));
+        assert_that!(toggled.get_status(), is(equal_to(Status::new(0, 1, 0, 0).with_ages([1, 0, 0, 0]))));
+    }
+
+    #[test]
+    fn with_toggled_cell_keeps_other_cells() {
+        let block = vec![
+            Cell::new(Place::new(0, 0)),
+            Cell::new(Place::new(1, 0)),
+            Cell::new(Place::new(0, 1)),
+            Cell::new(Place::new(1, 1)),
+        ];
+        let sut = Population::new(4, 4, block).unwrap().next_generation(&Rule::conway());
+
+        let toggled = sut.with_toggled_cell(&Place::new(3, 3));
+
+        assert_that!(toggled.get_cell(&Place::new(1, 1)), is(equal_to(Some(Cell::new(Place::new(1, 1)).grow_older()))));
+        assert_that!(toggled.get_cell(&Place::new(3, 3)), is(equal_to(Some(Cell::new(Place::new(3, 3))))));
+        assert_that!(toggled.get_status().get_iteration(), is(equal_to(1)));
+        assert_that!(toggled.get_status().get_cells(), is(equal_to(5)));
+    }
+
+    #[test]
+    fn with_toggled_cell_ignores_place_outside() {
+        let sut = Population::new(3, 3, vec![Cell::new(Place::new(0, 0))]).unwrap();
+
+        assert_that!(sut.clone().with_toggled_cell(&Place::new(3, 0)) == sut, is(true));
+    }
+
+    #[test]
+    fn next_generation_counts_ages() {
+        let block = vec![
+            Cell::new(Place::new(1, 1)), Cell::new(Place::new(2, 1)),
+            Cell::new(Place::new(1, 2)), Cell::new(Place::new(2, 2)),
+        ];
+        let mut sut = Population::new(4, 4, block).unwrap();
+
+        assert_that!(sut.get_status().get_ages(), is(equal_to(&[4, 0, 0, 0])));
+
+        for _ in 0..4 {
+            sut = sut.next_generation(&Rule::conway());
+        }
+
+        assert_that!(sut.get_status().get_ages(), is(equal_to(&[0, 0, 4, 0])));
+        assert_that!(sut.get_cell(&Place::new(1, 1)).map(|cell| cell.get_age()), is(equal_to(Some(4))));
+    }
+
+    #[test]
+    fn dead_cells_fade_along_trail() {
+        let sut = Population::new(3, 3, vec![Cell::new(Place::new(1, 1))]).unwrap().with_trail(3);
+        let rule = Rule::conway();
+
+        let first = sut.next_generation(&rule);
+        assert_that!(first.get_cell(&Place::new(1, 1)).map(|cell| cell.get_decay()), is(equal_to(Some(0))));
+
+        let third = first.next_generation(&rule).next_generation(&rule);
+        assert_that!(third.get_cell(&Place::new(1, 1)).map(|cell| cell.get_decay()), is(equal_to(Some(2))));
+        assert_that!(third.next_generation(&rule).has_cell(&Place::new(1, 1)), is(false));
+    }
+
+    #[test]
+    fn dead_cells_are_dropped_without_trail() {
+        let sut = Population::new(3, 3, vec![Cell::new(Place::new(1, 1))]).unwrap().with_trail(0);
+
+        assert_that!(sut.next_generation(&Rule::conway()).has_cell(&Place::new(1, 1)), is(false));
+    }
+
+    #[test]
+    fn cell_is_born_on_trail() {
+        let cells = vec![Cell::new(Place::new(0, 0)), Cell::new(Place::new(2, 0)), Cell::new(Place::new(1, 2))];
+        let sut = Population::new(3, 3, cells).unwrap().with_trail(5);
+
+        let next = sut.next_generation(&Rule::conway());
+
+        assert_that!(next.get_cell(&Place::new(1, 1)), is(equal_to(Some(Cell::new(Place::new(1, 1))))));
+        assert_that!(next.get_cell(&Place::new(0, 0)), is(equal_to(Some(Cell::new(Place::new(0, 0)).kill()))));
+    }
+
     #[test]
-    fn generate_line_for_population_zero_width() {
-        assert_that!(generate_line_for_population(0), is(equal_to(String::from("++"))));
+    fn cleared_has_no_cells() {
+        let sut = Population::new(3, 3, vec![Cell::new(Place::new(0, 0)), Cell::new(Place::new(2, 1))]).unwrap();
+
+        assert_that!(sut.cleared().get_living_places(), is(equal_to(Vec::new())));
     }
 
     #[test]
-    fn generate_line_for_population_some_width() {
-        assert_that!(generate_line_for_population(5), is(equal_to(String::from("+-----+"))));
+    fn filled_has_cells_everywhere() {
+        let sut = Population::new(2, 2, Vec::new()).unwrap().with_topology(Topology::Torus);
+
+        let filled = sut.filled();
+
+        assert_that!(
+            filled.get_living_places(),
+            is(equal_to(vec![Place::new(0, 0), Place::new(1, 0), Place::new(0, 1), Place::new(1, 1)])));
+        assert_that!(filled.topology, is(equal_to(Topology::Torus)));
+    }
+
+    #[test]
+    fn inverted_swaps_living_and_empty_places() {
+        let sut = Population::new(2, 2, vec![Cell::new(Place::new(0, 0)), Cell::new(Place::new(1, 1))]).unwrap();
+
+        assert_that!(sut.inverted().get_living_places(), is(equal_to(vec![Place::new(1, 0), Place::new(0, 1)])));
     }
 
     #[test]
     fn new_population_has_initial_status() {
-        let initial = Population::new(5, 5, Vec::new());
+        let initial = Population::new(5, 5, Vec::new()).unwrap();
 
         assert_that!(initial.get_status(), is(equal_to(Status::new(0, 0, 0, 0))));
     }
 
     #[test]
     fn generate_next_population_from_empty_population() {
-        let sut = Population::new(5, 5, Vec::new());
+        let sut = Population::new(5, 5, Vec::new()).unwrap();
 
-        let next = sut.next_generation();
+        let next = sut.next_generation(&Rule::conway());
 
         assert_that!(sut.get_status().get_iteration(), is(equal_to(0)));
         assert_that!(next.get_status().get_iteration(), is(equal_to(1)));
@@ -236,8 +544,8 @@ mod tests {
             Cell::new(Place::new(9, 4))
         ];
 
-        let sut = Population::new(10, 5, cells);
-        let next = sut.next_generation().get_status();
+        let sut = Population::new(10, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::conway()).get_status();
 
         assert_that!(next.get_cells(), is(equal_to(0)));
         assert_that!(next.get_died(), is(equal_to(1)));
@@ -250,8 +558,8 @@ mod tests {
             Cell::new(Place::new(9, 4))
         ];
 
-        let sut = Population::new(10, 5, cells);
-        let next = sut.next_generation().get_status();
+        let sut = Population::new(10, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::conway()).get_status();
 
         assert_that!(next.get_cells(), is(equal_to(0)));
         assert_that!(next.get_died(), is(equal_to(2)));
@@ -265,8 +573,8 @@ mod tests {
             Cell::new(Place::new(7, 3))
         ];
 
-        let sut = Population::new(10, 5, cells);
-        let next = sut.next_generation();
+        let sut = Population::new(10, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::conway());
 
         assert_that!(next.get_status().get_cells(), is(equal_to(3)));
         assert_that!(next.get_status().get_died(), is(equal_to(2)));
@@ -287,8 +595,8 @@ mod tests {
             Cell::new(Place::new(3, 3))
         ];
 
-        let sut = Population::new(10, 5, cells);
-        let next = sut.next_generation();
+        let sut = Population::new(10, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::conway());
 
         assert_that!(next.get_status().get_cells(), is(equal_to(7)));
         assert_that!(next.get_status().get_died(), is(equal_to(0)));
@@ -303,6 +611,86 @@ mod tests {
         assert_that!(next.has_cell(&Place::new(4, 3)), is(equal_to(true)));
     }
 
+    #[test]
+    fn generate_next_population_with_seeds_rule() {
+        let cells: Vec<Cell> = vec![
+            Cell::new(Place::new(2, 2)),
+            Cell::new(Place::new(3, 2))
+        ];
+
+        let sut = Population::new(6, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::parse("B2/S").unwrap());
+
+        assert_that!(next.get_status().get_cells(), is(equal_to(4)));
+        assert_that!(next.get_status().get_died(), is(equal_to(2)));
+        assert_that!(next.get_status().get_born(), is(equal_to(4)));
+        assert_that!(next.has_cell(&Place::new(2, 1)), is(equal_to(true)));
+        assert_that!(next.has_cell(&Place::new(3, 1)), is(equal_to(true)));
+        assert_that!(next.has_cell(&Place::new(2, 3)), is(equal_to(true)));
+        assert_that!(next.has_cell(&Place::new(3, 3)), is(equal_to(true)));
+    }
+
+    #[test]
+    fn generate_next_population_blinker_oscillates() {
+        let cells: Vec<Cell> = vec![
+            Cell::new(Place::new(5, 3)),
+            Cell::new(Place::new(6, 3)),
+            Cell::new(Place::new(7, 3))
+        ];
+
+        let sut = Population::new(10, 5, cells).unwrap();
+        let next = sut.next_generation(&Rule::conway()).next_generation(&Rule::conway());
+
+        assert_that!(next.get_status().get_cells(), is(equal_to(3)));
+        assert_that!(next.has_living_cell(&Place::new(5, 3)), is(equal_to(true)));
+        assert_that!(next.has_living_cell(&Place::new(6, 3)), is(equal_to(true)));
+        assert_that!(next.has_living_cell(&Place::new(7, 3)), is(equal_to(true)));
+        assert_that!(next.has_living_cell(&Place::new(6, 2)), is(equal_to(false)));
+        assert_that!(next.has_living_cell(&Place::new(6, 4)), is(equal_to(false)));
+    }
+
+    #[test]
+    fn count_neighbours_ignores_dead_cells() {
+        let cells = vec![
+            Cell::new(Place::new(4, 4)).kill(),
+            Cell::new(Place::new(5, 4)),
+            Cell::new(Place::new(5, 5))
+        ];
+
+        assert_that!(count_neighbours_on_plane(cells, &Place::new(4, 5)), is(equal_to(2)));
+    }
+
+    #[test]
+    fn generate_next_population_glider_dies_at_border_of_plane() {
+        let sut = Population::new(5, 5, glider_at_bottom_right()).unwrap();
+        let next = sut.next_generation(&Rule::conway());
+
+        assert_that!(next.get_status().get_cells(), is(equal_to(4)));
+    }
+
+    #[test]
+    fn generate_next_population_glider_wraps_around_torus() {
+        let sut = Population::new(5, 5, glider_at_bottom_right()).unwrap().with_topology(Topology::Torus);
+        let next = sut.next_generation(&Rule::conway());
+
+        assert_that!(next.get_status().get_cells(), is(equal_to(5)));
+        assert_that!(next.has_cell(&Place::new(3, 0)), is(equal_to(true)));
+    }
+
+    fn glider_at_bottom_right() -> Vec<Cell> {
+        //   012345
+        // 2   ☀
+        // 3    ☀
+        // 4  ☀☀☀
+        vec![
+            Cell::new(Place::new(3, 2)),
+            Cell::new(Place::new(4, 3)),
+            Cell::new(Place::new(2, 4)),
+            Cell::new(Place::new(3, 4)),
+            Cell::new(Place::new(4, 4))
+        ]
+    }
+
     #[test]
     #[ignore]
     fn generate_next_population_cell_with_four_neighbours_must_die() {}
@@ -329,7 +717,7 @@ mod tests {
 
     #[test]
     fn format_display_empty_population() {
-        let sut = Population::new(10, 5, Vec::new());
+        let sut = Population::new(10, 5, Vec::new()).unwrap();
         let expected = r#"+----------+
 |          |
 |          |
@@ -362,7 +750,7 @@ mod tests {
             Cell::new(Place::new(0, 4)),
             Cell::new(Place::new(9, 4))
         ];
-        let sut = Population::new(10, 5, cells);
+        let sut = Population::new(10, 5, cells).unwrap();
         let expected = r#"+----------+
 |☀        ☀|
 |☀ ☀    ☀ ☀|
@@ -377,7 +765,7 @@ mod tests {
 
     #[test]
     fn get_cell_not_found() {
-        let sut = Population::new(5, 5, Vec::new());
+        let sut = Population::new(5, 5, Vec::new()).unwrap();
 
         assert_that!(sut.get_cell(&Place::new(1, 1)), is(equal_to(None)));
     }
@@ -387,14 +775,14 @@ mod tests {
         let sut = Population::new(
             5,
             5,
-            vec![Cell::new(Place::new(1, 1))]);
+            vec![Cell::new(Place::new(1, 1))]).unwrap();
 
         assert_that!(sut.get_cell(&Place::new(1, 1)), is(equal_to(Some(Cell::new(Place::new(1, 1))))));
     }
 
     #[test]
     fn has_cell_not_found() {
-        let sut = Population::new(5, 5, Vec::new());
+        let sut = Population::new(5, 5, Vec::new()).unwrap();
 
         assert_that!(sut.has_cell(&Place::new(1, 1)), is(equal_to(false)));
     }
@@ -404,11 +792,31 @@ mod tests {
         let sut = Population::new(
             5,
             5,
-            vec![Cell::new(Place::new(1, 1))]);
+            vec![Cell::new(Place::new(1, 1))]).unwrap();
 
         assert_that!(sut.has_cell(&Place::new(1, 1)), is(equal_to(true)));
     }
 
+    #[test]
+    fn get_living_places_ordered_without_dead_cells() {
+        let sut = Population::new(
+            5,
+            5,
+            vec![Cell::new(Place::new(3, 1)), Cell::new(Place::new(1, 1)).kill(), Cell::new(Place::new(4, 0))]).unwrap();
+
+        assert_that!(sut.get_living_places(), is(equal_to(vec![Place::new(4, 0), Place::new(3, 1)])));
+    }
+
+    #[test]
+    fn get_state_hash_ignores_dead_cells_and_status() {
+        let sut = Population::new(5, 5, vec![Cell::new(Place::new(1, 1)), Cell::new(Place::new(2, 1))]).unwrap();
+        let other = Population::new(5, 5, vec![Cell::new(Place::new(2, 1)), Cell::new(Place::new(1, 1))]).unwrap()
+            .next_generation(&Rule::parse("B/S1").unwrap());
+
+        assert_that!(sut.get_state_hash(), is(equal_to(other.get_state_hash())));
+        assert_that!(sut.get_state_hash() == sut.next_generation(&Rule::conway()).get_state_hash(), is(false));
+    }
+
     #[test]
     fn get_number_of_cells_in_status() {
         let cells: Vec<Cell> = vec![
@@ -419,104 +827,14 @@ mod tests {
             Cell::new(Place::new(7, 1))
         ];
 
-        let sut = Population::new(10, 5, cells);
+        let sut = Population::new(10, 5, cells).unwrap();
 
         assert_that!(sut.get_status().get_cells(), is(equal_to(5)));
     }
 
-    #[test]
-    fn cell_must_die_if_zero_neighbours() {
-        assert_that!(should_die(0), is(true));
-    }
-
-    #[test]
-    fn cell_must_die_if_one_neighbours() {
-        assert_that!(should_die(1), is(true));
-    }
-
-    #[test]
-    fn cell_must_not_die_if_two_neighbours() {
-        assert_that!(should_die(2), is(false));
-    }
-
-    #[test]
-    fn cell_must_not_die_if_three_neighbours() {
-        assert_that!(should_die(3), is(false));
-    }
-
-    #[test]
-    fn cell_must_die_if_four_neighbours() {
-        assert_that!(should_die(4), is(true));
-    }
-
-    #[test]
-    fn cell_must_die_if_five_neighbours() {
-        assert_that!(should_die(5), is(true));
-    }
-
-    #[test]
-    fn cell_must_die_if_six_neighbours() {
-        assert_that!(should_die(6), is(true));
-    }
-
-    #[test]
-    fn cell_must_die_if_seven_neighbours() {
-        assert_that!(should_die(7), is(true));
-    }
-
-    #[test]
-    fn cell_must_die_if_eight_neighbours() {
-        assert_that!(should_die(8), is(true));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_zero_neighbours() {
-        assert_that!(should_spawn(0), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_one_neighbours() {
-        assert_that!(should_spawn(1), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_two_neighbours() {
-        assert_that!(should_spawn(2), is(false));
-    }
-
-    #[test]
-    fn should_spawn_new_cell_if_three_neighbours() {
-        assert_that!(should_spawn(3), is(true));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_four_neighbours() {
-        assert_that!(should_spawn(4), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_five_neighbours() {
-        assert_that!(should_spawn(5), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_six_neighbours() {
-        assert_that!(should_spawn(6), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_seven_neighbours() {
-        assert_that!(should_spawn(7), is(false));
-    }
-
-    #[test]
-    fn should_not_spawn_new_cell_if_eight_neighbours() {
-        assert_that!(should_spawn(8), is(false));
-    }
-
     #[test]
     fn count_neighbours_empty_vector() {
-        assert_that!(count_neighbours(&Vec::new(), &Place::new(1, 1)), is(equal_to(0)));
+        assert_that!(count_neighbours_on_plane(Vec::new(), &Place::new(1, 1)), is(equal_to(0)));
     }
 
     #[test]
@@ -527,7 +845,7 @@ mod tests {
             Cell::new(Place::new(6, 7))
         ];
 
-        assert_that!(count_neighbours(&cells, &Place::new(5, 5)), is(equal_to(0)));
+        assert_that!(count_neighbours_on_plane(cells, &Place::new(5, 5)), is(equal_to(0)));
     }
 
     #[test]
@@ -538,7 +856,7 @@ mod tests {
             Cell::new(Place::new(6, 6))
         ];
 
-        assert_that!(count_neighbours(&cells, &Place::new(5, 5)), is(equal_to(2)));
+        assert_that!(count_neighbours_on_plane(cells, &Place::new(5, 5)), is(equal_to(2)));
     }
 
     #[test]
@@ -558,6 +876,6 @@ mod tests {
             Cell::new(Place::new(6, 6))
         ];
 
-        assert_that!(count_neighbours(&cells, &Place::new(5, 5)), is(equal_to(8)));
+        assert_that!(count_neighbours_on_plane(cells, &Place::new(5, 5)), is(equal_to(8)));
     }
 }
\ No newline at end of file
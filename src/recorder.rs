@@ -0,0 +1,164 @@
+use std::fs;
+use std::io;
+use std::time::Duration;
+use crate::error::Error;
+use crate::gif::Gif;
+use crate::image::{Image, ImageStyle};
+use crate::population::Population;
+use crate::simulation::{Observer, Step};
+
+/// Default maximum number of frames recorded.
+pub const DEFAULT_MAX_FRAMES: usize = 500;
+
+/// This struct records generations of a population into an animated GIF.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Recorder {
+    style: ImageStyle,
+    /// First generation recorded.
+    from: usize,
+    /// Optional last generation recorded.
+    to: Option<usize>,
+    /// Maximum number of frames recorded, later generations are dropped.
+    max_frames: usize,
+    /// Whether the animation starts over after the last frame.
+    repeat: bool,
+    /// Time each frame is shown.
+    delay: Duration,
+    /// The animation, created with the first recorded frame.
+    gif: Option<Gif>,
+}
+
+impl Recorder {
+    /// Creates a recorder which draws the generations in the given style and shows each of them
+    /// for the given duration.
+    pub fn new(style: ImageStyle, delay: Duration) -> Recorder {
+        Recorder {
+            style,
+            from: 0,
+            to: None,
+            max_frames: DEFAULT_MAX_FRAMES,
+            repeat: false,
+            delay,
+            gif: None,
+        }
+    }
+
+    /// Creates a copy of this recorder which only records the generations from the first to the
+    /// optional last one given, both included.
+    pub fn with_range(self, from: usize, to: Option<usize>) -> Recorder {
+        Recorder { from, to, ..self }
+    }
+
+    /// Creates a copy of this recorder which records at most the given number of frames.
+    pub fn with_max_frames(self, max_frames: usize) -> Recorder {
+        Recorder { max_frames, ..self }
+    }
+
+    /// Creates a copy of this recorder whose animation starts over after the last frame.
+    pub fn with_loop(self) -> Recorder {
+        Recorder { repeat: true, ..self }
+    }
+
+    /// Records the given population as next frame if its generation is in the recorded range and
+    /// the maximum number of frames is not reached yet.
+    pub fn record(&mut self, population: &Population) {
+        let generation = population.get_status().get_iteration();
+
+        if generation < self.from || self.to.is_some_and(|to| generation > to) || self.get_frames() >= self.max_frames {
+            return;
+        }
+
+        let image = Image::of(population, &self.style);
+        let delay = self.delay;
+        let repeat = self.repeat;
+        let gif = self.gif.get_or_insert_with(|| {
+            let gif = Gif::new(image.get_width(), image.get_height(), delay);
+
+            if repeat {
+                gif.with_loop()
+            } else {
+                gif
+            }
+        });
+
+        gif.add_frame(&image);
+    }
+
+    /// Number of frames recorded so far.
+    pub fn get_frames(&self) -> usize {
+        self.gif.as_ref().map_or(0, Gif::get_frames)
+    }
+
+    /// Saves the recorded animation to the given file.
+    ///
+    /// Nothing is saved if no frame was recorded.
+    pub fn save(&self, file: &str) -> Result<(), Error> {
+        let action = || format!("write recording file '{}'", file);
+
+        match self.gif {
+            Some(ref gif) => {
+                let content = gif.encode()
+                    .map_err(|reason| Error::io(action(), io::Error::new(io::ErrorKind::InvalidInput, reason)))?;
+
+                fs::write(file, content).map_err(|err| Error::io(action(), err))
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+impl Observer for Recorder {
+    fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+        self.record(population);
+        Ok(Step::Continue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::population::create_blinker;
+    use crate::rule::Rule;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn record_only_generations_in_range() {
+        let mut sut = Recorder::new(ImageStyle::new(), Duration::from_millis(100)).with_range(1, Some(3));
+        let mut population = create_blinker();
+
+        for _ in 0..6 {
+            sut.record(&population);
+            population = population.next_generation(&Rule::conway());
+        }
+
+        assert_that!(sut.get_frames(), is(equal_to(3)));
+    }
+
+    #[test]
+    fn record_at_most_max_frames() {
+        let mut sut = Recorder::new(ImageStyle::new(), Duration::from_millis(100)).with_max_frames(2);
+        let mut population = create_blinker();
+
+        for _ in 0..6 {
+            sut.record(&population);
+            population = population.next_generation(&Rule::conway());
+        }
+
+        assert_that!(sut.get_frames(), is(equal_to(2)));
+    }
+
+    #[test]
+    fn save_too_large_recording() {
+        let population = Population::new(70000, 1, Vec::new()).unwrap();
+        let mut sut = Recorder::new(ImageStyle::new().with_scale(1), Duration::from_millis(100));
+        sut.record(&population);
+
+        match sut.save("never-written.gif") {
+            Err(Error::Io { action, source }) => {
+                assert_that!(action, is(equal_to(String::from("write recording file 'never-written.gif'"))));
+                assert_that!(source.kind(), is(equal_to(io::ErrorKind::InvalidInput)));
+            },
+            _ => panic!("Expected too large recording to be rejected!"),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+
+/// Dots of a Braille pattern by row and column of the 2x4 places of a character.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+/// The Braille pattern without any dots.
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// This enum describes how many places are packed into one character on the screen.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Render {
+    /// Each place is one character, so living and dying cells can be told apart.
+    Cells,
+    /// Each character shows two places on top of each other with half-block glyphs.
+    HalfBlocks,
+    /// Each character shows 2x4 places with Braille patterns.
+    Braille,
+}
+
+impl Render {
+    /// Parses a render mode from its name (`cells`, `half-blocks` or `braille`).
+    pub fn parse(name: &str) -> Result<Render, String> {
+        match name.trim().to_lowercase().as_str() {
+            "cells" => Ok(Render::Cells),
+            "half-blocks" => Ok(Render::HalfBlocks),
+            "braille" => Ok(Render::Braille),
+            _ => Err(format!("Unknown render mode '{}'! Expected 'cells', 'half-blocks' or 'braille'.", name)),
+        }
+    }
+
+    /// Number of places shown side by side in one character.
+    pub fn get_width(&self) -> usize {
+        match *self {
+            Render::Cells | Render::HalfBlocks => 1,
+            Render::Braille => 2,
+        }
+    }
+
+    /// Number of places shown on top of each other in one character.
+    pub fn get_height(&self) -> usize {
+        match *self {
+            Render::Cells => 1,
+            Render::HalfBlocks => 2,
+            Render::Braille => 4,
+        }
+    }
+
+    /// The character showing which of its places are living, given by row and column.
+    ///
+    /// For single places this is the glyph of a living cell, because dying cells are not part of
+    /// the given places.
+    pub fn get_glyph(&self, living: &[Vec<bool>]) -> char {
+        let is_living = |row: usize, column: usize| {
+            living.get(row).and_then(|places| places.get(column)).cloned().unwrap_or(false)
+        };
+
+        match *self {
+            Render::Cells => if is_living(0, 0) { '☀' } else { ' ' },
+            Render::HalfBlocks => match (is_living(0, 0), is_living(1, 0)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            },
+            Render::Braille => {
+                let mut dots = 0;
+
+                for (row, columns) in BRAILLE_DOTS.iter().enumerate() {
+                    for (column, dot) in columns.iter().enumerate() {
+                        if is_living(row, column) {
+                            dots |= dot;
+                        }
+                    }
+                }
+
+                if dots == 0 {
+                    ' '
+                } else {
+                    std::char::from_u32(BRAILLE_BLANK + dots).unwrap_or(' ')
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for Render {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Render::Cells => write!(f, "cells"),
+            Render::HalfBlocks => write!(f, "half-blocks"),
+            Render::Braille => write!(f, "braille"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn format_render() {
+        assert_that!(format!("{}", Render::Cells), is(equal_to(String::from("cells"))));
+        assert_that!(format!("{}", Render::HalfBlocks), is(equal_to(String::from("half-blocks"))));
+        assert_that!(format!("{}", Render::Braille), is(equal_to(String::from("braille"))));
+    }
+
+    #[test]
+    fn parse_known_render_modes() {
+        assert_that!(Render::parse("cells"), is(equal_to(Ok(Render::Cells))));
+        assert_that!(Render::parse("Half-Blocks"), is(equal_to(Ok(Render::HalfBlocks))));
+        assert_that!(Render::parse("braille"), is(equal_to(Ok(Render::Braille))));
+    }
+
+    #[test]
+    fn parse_unknown_render_mode() {
+        assert_that!(
+            Render::parse("ascii"),
+            is(equal_to(Err(String::from(
+                "Unknown render mode 'ascii'! Expected 'cells', 'half-blocks' or 'braille'.")))));
+    }
+
+    #[test]
+    fn get_glyph_of_half_blocks() {
+        assert_that!(Render::HalfBlocks.get_glyph(&[vec![true], vec![true]]), is(equal_to('█')));
+        assert_that!(Render::HalfBlocks.get_glyph(&[vec![true], vec![false]]), is(equal_to('▀')));
+        assert_that!(Render::HalfBlocks.get_glyph(&[vec![false], vec![true]]), is(equal_to('▄')));
+        assert_that!(Render::HalfBlocks.get_glyph(&[vec![false], vec![false]]), is(equal_to(' ')));
+    }
+
+    #[test]
+    fn get_glyph_of_braille() {
+        let living = vec![
+            vec![true, false],
+            vec![false, true],
+            vec![false, false],
+            vec![true, true],
+        ];
+
+        assert_that!(Render::Braille.get_glyph(&living), is(equal_to('⣑')));
+        assert_that!(Render::Braille.get_glyph(&[vec![false; 2], vec![false; 2]]), is(equal_to(' ')));
+        assert_that!(Render::Braille.get_glyph(&vec![vec![true; 2]; 4]), is(equal_to('⣿')));
+    }
+}
@@ -0,0 +1,217 @@
+use termion::clear;
+use termion::cursor::Goto;
+use unicode_width::UnicodeWidthChar;
+
+/// Resets all colours and attributes of the terminal.
+static RESET_STYLE: &str = "\x1b[0m";
+/// The escape character which starts control sequences.
+const ESCAPE: char = '\x1b';
+
+/// This struct renders frames by only rewriting the terminal cells which changed since the
+/// previous frame.
+///
+/// A frame is plain text with lines separated by line feeds. Colours and attributes given as SGR
+/// control sequences are kept per terminal cell, any other control sequence is dropped. Wide
+/// characters are followed by empty cells for the further terminal cells they cover.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Renderer {
+    /// The cells of the previous frame by row and column.
+    previous: Vec<Vec<String>>,
+}
+
+impl Renderer {
+    /// Creates a renderer which has not rendered any frame yet.
+    pub fn new() -> Renderer {
+        Renderer { previous: Vec::new() }
+    }
+
+    /// Returns the output which turns the previous frame on the terminal into the given one.
+    ///
+    /// The first frame is drawn onto a cleared screen.
+    pub fn render(&mut self, frame: &str) -> String {
+        let current = split_cells(frame);
+        let mut out = String::new();
+        // Position right after the last written cell, so adjacent cells need no cursor movement.
+        let mut cursor: Option<(usize, usize)> = None;
+
+        if self.previous.is_empty() {
+            out.push_str(&format!("{}", clear::All));
+        }
+
+        for y in 0..current.len().max(self.previous.len()) {
+            let current_row = current.get(y);
+            let previous_row = self.previous.get(y);
+            let width = current_row.map_or(0, Vec::len).max(previous_row.map_or(0, Vec::len));
+
+            for x in 0..width {
+                let current_cell = get_cell(current_row, x);
+
+                // The covered cells of a wide character are written together with it.
+                if current_cell == get_cell(previous_row, x) || current_cell.is_empty() {
+                    continue;
+                }
+
+                if cursor != Some((x, y)) {
+                    out.push_str(&format!("{}", Goto(x as u16 + 1, y as u16 + 1)));
+                }
+
+                out.push_str(current_cell);
+
+                if current_cell.starts_with(ESCAPE) {
+                    out.push_str(RESET_STYLE);
+                }
+
+                cursor = Some((x + get_cell_width(current_cell), y));
+            }
+        }
+
+        self.previous = current;
+        out
+    }
+}
+
+/// Cells outside of a frame are blank.
+fn get_cell(row: Option<&Vec<String>>, x: usize) -> &str {
+    row.and_then(|row| row.get(x)).map_or(" ", String::as_str)
+}
+
+/// Number of terminal cells the character of the given cell covers.
+fn get_cell_width(cell: &str) -> usize {
+    cell.chars().last().and_then(UnicodeWidthChar::width).unwrap_or(1).max(1)
+}
+
+/// Splits a frame into rows of terminal cells, each with the style it is printed in.
+fn split_cells(frame: &str) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = vec![Vec::new()];
+    let mut style = String::new();
+    let mut chars = frame.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        match character {
+            ESCAPE => {
+                let mut sequence = String::new();
+                sequence.push(character);
+
+                if chars.peek() == Some(&'[') {
+                    chars.next();
+                    sequence.push('[');
+
+                    for next in chars.by_ref() {
+                        sequence.push(next);
+
+                        if ('\x40'..='\x7e').contains(&next) {
+                            break;
+                        }
+                    }
+                }
+
+                if sequence == RESET_STYLE || sequence == "\x1b[m" {
+                    style.clear();
+                } else if sequence.ends_with('m') {
+                    style.push_str(&sequence);
+                }
+            },
+            '\n' => rows.push(Vec::new()),
+            '\r' => {},
+            _ => {
+                let mut cell = style.clone();
+                cell.push(character);
+
+                if let Some(row) = rows.last_mut() {
+                    row.push(cell);
+
+                    for _ in 1..character.width().unwrap_or(1) {
+                        row.push(String::new());
+                    }
+                }
+            },
+        }
+    }
+
+    if rows.last().is_some_and(Vec::is_empty) {
+        rows.pop();
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn split_cells_by_line() {
+        assert_that!(
+            split_cells("ab\nc\n"),
+            is(equal_to(vec![
+                vec![String::from("a"), String::from("b")],
+                vec![String::from("c")]])));
+    }
+
+    #[test]
+    fn split_cells_keeps_style_until_reset() {
+        assert_that!(
+            split_cells("a\x1b[7mb\x1b[1mc\x1b[0md\x1b[2J"),
+            is(equal_to(vec![vec![
+                String::from("a"),
+                String::from("\x1b[7mb"),
+                String::from("\x1b[7m\x1b[1mc"),
+                String::from("d")]])));
+    }
+
+    #[test]
+    fn split_cells_covers_wide_characters() {
+        assert_that!(
+            split_cells("a🌕b"),
+            is(equal_to(vec![vec![String::from("a"), String::from("🌕"), String::new(), String::from("b")]])));
+    }
+
+    #[test]
+    fn render_wide_characters() {
+        let mut sut = Renderer::new();
+        sut.render("a  b");
+
+        assert_that!(sut.render("a🌕b"), is(equal_to(String::from("\x1b[1;2H🌕"))));
+        assert_that!(sut.render("ax b"), is(equal_to(String::from("\x1b[1;2Hx "))));
+    }
+
+    #[test]
+    fn render_first_frame_clears_screen() {
+        let mut sut = Renderer::new();
+
+        assert_that!(sut.render("ab\nc"), is(equal_to(String::from("\x1b[2J\x1b[1;1Hab\x1b[2;1Hc"))));
+    }
+
+    #[test]
+    fn render_only_changed_cells() {
+        let mut sut = Renderer::new();
+        sut.render("abc\ndef");
+
+        assert_that!(sut.render("abc\ndxy"), is(equal_to(String::from("\x1b[2;2Hxy"))));
+    }
+
+    #[test]
+    fn render_unchanged_frame_writes_nothing() {
+        let mut sut = Renderer::new();
+        sut.render("abc\ndef");
+
+        assert_that!(sut.render("abc\ndef"), is(equal_to(String::new())));
+    }
+
+    #[test]
+    fn render_blanks_removed_cells() {
+        let mut sut = Renderer::new();
+        sut.render("abc\nd");
+
+        assert_that!(sut.render("a"), is(equal_to(String::from("\x1b[1;2H  \x1b[2;1H "))));
+    }
+
+    #[test]
+    fn render_resets_style_after_styled_cell() {
+        let mut sut = Renderer::new();
+        sut.render("ab");
+
+        assert_that!(sut.render("a\x1b[7mb\x1b[0m"), is(equal_to(String::from("\x1b[1;2H\x1b[7mb\x1b[0m"))));
+    }
+}
@@ -0,0 +1,398 @@
+//! Support for the [Run Length Encoded][rle] pattern file format.
+//!
+//! ```text
+//! #N Glider
+//! #C A small spaceship.
+//! x = 3, y = 3, rule = B3/S23
+//! bob$2bo$3o!
+//! ```
+//!
+//! [rle]: http://www.conwaylife.com/wiki/Run_Length_Encoded
+use crate::pattern::Pattern;
+use crate::place::Place;
+use crate::rule::Rule;
+
+/// Parses a pattern in RLE format.
+///
+/// The comment lines `#N` (name), `#O` (author) and `#C`/`#c` (comments) are kept, all other
+/// comment lines are ignored. The header line with the size is required, the rule in it is
+/// optional.
+pub fn parse(input: &str) -> Result<Pattern, String> {
+    let mut name = None;
+    let mut author = None;
+    let mut comments: Vec<String> = Vec::new();
+    let mut header: Option<Header> = None;
+    let mut body = String::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+
+        if header.is_none() {
+            if line.is_empty() {
+                continue;
+            } else if line.starts_with('#') {
+                let text = line.get(2..).unwrap_or("").trim().to_string();
+
+                match line.get(..2) {
+                    Some("#N") => name = Some(text),
+                    Some("#O") => author = Some(text),
+                    Some("#C") | Some("#c") => comments.push(text),
+                    _ => {},
+                }
+            } else {
+                header = Some(parse_header(line).map_err(|reason| format!("Line {}: {}", index + 1, reason))?);
+            }
+        } else {
+            body.push_str(line);
+
+            if line.contains('!') {
+                break;
+            }
+        }
+    }
+
+    let header = match header {
+        Some(header) => header,
+        None => return Err(String::from("Missing header line 'x = ..., y = ...'!")),
+    };
+    let cells = parse_body(&body, &header)?;
+    let mut pattern = Pattern::new(header.width, header.height, cells)?.with_comments(comments);
+
+    if let Some(name) = name {
+        pattern = pattern.with_name(name);
+    }
+
+    if let Some(author) = author {
+        pattern = pattern.with_author(author);
+    }
+
+    if let Some(rule) = header.rule {
+        pattern = pattern.with_rule(rule);
+    }
+
+    Ok(pattern)
+}
+
+/// Maximum length of lines written.
+const MAX_LINE_LENGTH: usize = 70;
+
+/// Writes a pattern in RLE format.
+///
+/// Lines of the encoded cells are wrapped so that they do not exceed 70 characters.
+pub fn write(pattern: &Pattern) -> String {
+    let mut output = String::new();
+
+    if let Some(name) = pattern.get_name() {
+        output.push_str(&format!("#N {}\n", name));
+    }
+
+    if let Some(author) = pattern.get_author() {
+        output.push_str(&format!("#O {}\n", author));
+    }
+
+    for comment in pattern.get_comments() {
+        output.push_str(&format!("#C {}\n", comment));
+    }
+
+    output.push_str(&format!("x = {}, y = {}", pattern.get_width(), pattern.get_height()));
+
+    if let Some(rule) = pattern.get_rule() {
+        output.push_str(&format!(", rule = {}", rule));
+    }
+
+    output.push('\n');
+
+    let mut line = String::new();
+
+    for token in encode_cells(pattern.get_cells()) {
+        if line.len() + token.len() > MAX_LINE_LENGTH {
+            output.push_str(&line);
+            output.push('\n');
+            line.clear();
+        }
+
+        line.push_str(&token);
+    }
+
+    output.push_str(&line);
+    output.push('\n');
+    output
+}
+
+/// Encodes the cells into run length tokens, terminated by `!`.
+fn encode_cells(cells: &[Place]) -> Vec<String> {
+    let mut cells = cells.to_vec();
+    cells.sort_by_key(|place| (place.get_y(), place.get_x()));
+
+    let mut tokens: Vec<String> = Vec::new();
+    let mut x: usize = 0;
+    let mut y: usize = 0;
+    let mut alive = 0;
+
+    for place in cells {
+        if place.get_y() > y || place.get_x() > x {
+            push_token(&mut tokens, alive, 'o');
+            alive = 0;
+        }
+
+        if place.get_y() > y {
+            push_token(&mut tokens, place.get_y() - y, '$');
+            x = 0;
+            y = place.get_y();
+        }
+
+        if place.get_x() > x {
+            push_token(&mut tokens, place.get_x() - x, 'b');
+        }
+
+        alive += 1;
+        x = place.get_x() + 1;
+    }
+
+    push_token(&mut tokens, alive, 'o');
+    tokens.push(String::from("!"));
+    tokens
+}
+
+fn push_token(tokens: &mut Vec<String>, count: usize, tag: char) {
+    match count {
+        0 => {},
+        1 => tokens.push(tag.to_string()),
+        _ => tokens.push(format!("{}{}", count, tag)),
+    }
+}
+
+/// The values of the header line.
+struct Header {
+    width: usize,
+    height: usize,
+    rule: Option<Rule>,
+}
+
+fn parse_header(line: &str) -> Result<Header, String> {
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+
+    for assignment in line.split(',') {
+        let mut parts = assignment.splitn(2, '=');
+        let key = parts.next().unwrap_or("").trim();
+        let value = match parts.next() {
+            Some(value) => value.trim(),
+            // Belongs to a bounded grid suffix of the rule.
+            None => continue,
+        };
+
+        match key {
+            "x" => width = Some(parse_size(key, value)?),
+            "y" => height = Some(parse_size(key, value)?),
+            // Bounded grid suffixes like `:T40,30` are not supported and ignored.
+            "rule" => rule = Some(Rule::parse(value.split(':').next().unwrap_or(value))?),
+            _ => {},
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok(Header { width, height, rule }),
+        _ => Err(String::from("Header must contain 'x' and 'y'!")),
+    }
+}
+
+fn parse_size(key: &str, value: &str) -> Result<usize, String> {
+    value.parse::<usize>().map_err(|_| format!("Not negative number expected for '{}' in header!", key))
+}
+
+fn parse_body(body: &str, header: &Header) -> Result<Vec<Place>, String> {
+    let mut cells: Vec<Place> = Vec::new();
+    let mut x: usize = 0;
+    let mut y: usize = 0;
+    let mut count: Option<usize> = None;
+
+    for tag in body.chars() {
+        if let Some(digit) = tag.to_digit(10) {
+            count = count.unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|count| count.checked_add(digit as usize));
+
+            if count.is_none() {
+                return Err(String::from("Run count in pattern is too large!"));
+            }
+
+            continue;
+        }
+
+        let run = count.take().unwrap_or(1);
+
+        match tag {
+            '!' => break,
+            '$' => {
+                x = 0;
+                y = y.saturating_add(run);
+            },
+            'b' | '.' => x = x.saturating_add(run),
+            'A'..='Z' | 'a'..='z' => {
+                for _ in 0..run {
+                    if x >= header.width || y >= header.height {
+                        return Err(format!(
+                            "Cell at ({}, {}) lies outside of the pattern size {}x{}!",
+                            x, y, header.width, header.height));
+                    }
+
+                    cells.push(Place::new(x, y));
+                    x += 1;
+                }
+            },
+            _ if tag.is_whitespace() => {},
+            _ => return Err(format!("Invalid character '{}' in pattern!", tag)),
+        }
+    }
+
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn write_glider() {
+        let pattern = Pattern::new(
+            3,
+            3,
+            vec![Place::new(1, 0), Place::new(2, 1), Place::new(0, 2), Place::new(1, 2), Place::new(2, 2)]).unwrap()
+            .with_name(String::from("Glider"))
+            .with_author(String::from("Richard K. Guy"))
+            .with_comments(vec![String::from("A small spaceship.")])
+            .with_rule(Rule::conway());
+        let expected = r#"#N Glider
+#O Richard K. Guy
+#C A small spaceship.
+x = 3, y = 3, rule = B3/S23
+bo$2bo$3o!
+"#;
+
+        assert_that!(write(&pattern), is(equal_to(String::from(expected))));
+    }
+
+    #[test]
+    fn write_empty_pattern() {
+        assert_that!(write(&Pattern::new(0, 0, Vec::new()).unwrap()), is(equal_to(String::from("x = 0, y = 0\n!\n"))));
+    }
+
+    #[test]
+    fn write_with_empty_rows_and_gaps() {
+        let pattern = Pattern::new(12, 4, vec![Place::new(11, 0), Place::new(0, 3), Place::new(1, 3), Place::new(5, 3)]).unwrap();
+
+        assert_that!(write(&pattern), is(equal_to(String::from("x = 12, y = 4\n11bo3$2o3bo!\n"))));
+    }
+
+    #[test]
+    fn write_wraps_long_lines() {
+        let cells: Vec<Place> = (0..50).map(|x| Place::new(x * 2, 0)).collect();
+        let pattern = Pattern::new(99, 1, cells.clone()).unwrap();
+        let written = write(&pattern);
+
+        for line in written.lines() {
+            assert_that!(line.len() <= MAX_LINE_LENGTH, is(true));
+        }
+
+        assert_that!(written.lines().count(), is(equal_to(3)));
+        assert_that!(parse(&written), is(equal_to(Ok(pattern))));
+    }
+
+    #[test]
+    fn parse_glider() {
+        let input = r#"#N Glider
+#O Richard K. Guy
+#C The smallest, most common, and first discovered spaceship.
+#C www.conwaylife.com/wiki/index.php?title=Glider
+x = 3, y = 3, rule = B3/S23
+bob$2bo$3o!
+"#;
+        let expected = Pattern::new(
+            3,
+            3,
+            vec![Place::new(1, 0), Place::new(2, 1), Place::new(0, 2), Place::new(1, 2), Place::new(2, 2)]).unwrap()
+            .with_name(String::from("Glider"))
+            .with_author(String::from("Richard K. Guy"))
+            .with_comments(vec![
+                String::from("The smallest, most common, and first discovered spaceship."),
+                String::from("www.conwaylife.com/wiki/index.php?title=Glider")])
+            .with_rule(Rule::conway());
+
+        assert_that!(parse(input), is(equal_to(Ok(expected))));
+    }
+
+    #[test]
+    fn parse_without_rule_and_comments() {
+        let expected = Pattern::new(3, 1, vec![Place::new(0, 0), Place::new(1, 0), Place::new(2, 0)]).unwrap();
+
+        assert_that!(parse("x = 3, y = 1\n3o!"), is(equal_to(Ok(expected))));
+    }
+
+    #[test]
+    fn parse_body_over_multiple_lines_with_empty_rows() {
+        let expected = Pattern::new(2, 3, vec![Place::new(0, 0), Place::new(1, 2)]).unwrap()
+            .with_rule(Rule::parse("B36/S23").unwrap());
+
+        assert_that!(parse("x=2,y=3,rule=B36/S23\no\n2$\nbo!\nignored"), is(equal_to(Ok(expected))));
+    }
+
+    #[test]
+    fn parse_ignores_bounded_grid_suffix() {
+        let result = parse("x = 1, y = 1, rule = B3/S23:T40,30\no!").unwrap();
+
+        assert_that!(result.get_rule(), is(equal_to(Some(&Rule::conway()))));
+    }
+
+    #[test]
+    fn parse_without_header() {
+        assert_that!(parse("#N Nothing\n"), is(equal_to(Err(String::from("Missing header line 'x = ..., y = ...'!")))));
+    }
+
+    #[test]
+    fn parse_with_bad_size() {
+        assert_that!(
+            parse("#N Foo\nx = a, y = 1\no!"),
+            is(equal_to(Err(String::from("Line 2: Not negative number expected for 'x' in header!")))));
+    }
+
+    #[test]
+    fn parse_with_digits_only_rule() {
+        let result = parse("x = 1, y = 1, rule = 23/3\no!").unwrap();
+
+        assert_that!(result.get_rule(), is(equal_to(Some(&Rule::conway()))));
+    }
+
+    #[test]
+    fn parse_with_too_large_run_count() {
+        assert_that!(
+            parse("x = 1, y = 1\n99999999999999999999o!"),
+            is(equal_to(Err(String::from("Run count in pattern is too large!")))));
+    }
+
+    #[test]
+    fn parse_with_missing_size() {
+        assert_that!(parse("x = 1\no!"), is(equal_to(Err(String::from("Line 1: Header must contain 'x' and 'y'!")))));
+    }
+
+    #[test]
+    fn parse_with_bad_rule() {
+        assert_that!(
+            parse("x = 1, y = 1, rule = foo\no!"),
+            is(equal_to(Err(String::from("Line 1: Rule 'foo' must consist of a B and a S part separated by '/'!")))));
+    }
+
+    #[test]
+    fn parse_with_cell_outside_of_size() {
+        assert_that!(
+            parse("x = 2, y = 1\n3o!"),
+            is(equal_to(Err(String::from("Cell at (2, 0) lies outside of the pattern size 2x1!")))));
+    }
+
+    #[test]
+    fn parse_with_invalid_character() {
+        assert_that!(parse("x = 2, y = 1\no*!"), is(equal_to(Err(String::from("Invalid character '*' in pattern!")))));
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt;
+
+/// Maximum number of neighbours a cell can have.
+const MAX_NEIGHBOURS: usize = 8;
+
+/// This struct describes an outer-totalistic rule which decides if cells are born or survive.
+///
+/// Rules are given in the common notation `B3/S23` (birth/survival) or `S23/B3`
+/// (survival/birth). The digits list the number of living neighbours which lead to a birth at an
+/// empty place or let a living cell survive.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rule {
+    /// Indexed by number of neighbours: Whether a new cell is born.
+    birth: [bool; MAX_NEIGHBOURS + 1],
+    /// Indexed by number of neighbours: Whether a living cell survives.
+    survival: [bool; MAX_NEIGHBOURS + 1],
+}
+
+impl Rule {
+    /// Creates the classic rule of Conway's Game of Life: `B3/S23`.
+    pub fn conway() -> Rule {
+        Rule {
+            birth: [false, false, false, true, false, false, false, false, false],
+            survival: [false, false, true, true, false, false, false, false, false],
+        }
+    }
+
+    /// Parses a rule from `B.../S...` or `S.../B...` notation.
+    ///
+    /// The letters are case insensitive and both parts must be given, but they may be empty
+    /// (e.g. `B2/S` for Seeds). Without letters the older form `23/3` is assumed, which lists the
+    /// survival digits first.
+    pub fn parse(rule: &str) -> Result<Rule, String> {
+        let parts: Vec<&str> = rule.trim().split('/').collect();
+
+        if parts.len() != 2 {
+            return Err(format!("Rule '{}' must consist of a B and a S part separated by '/'!", rule));
+        }
+
+        if parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit())) {
+            return Ok(Rule {
+                birth: parse_neighbour_counts(parts[1])?,
+                survival: parse_neighbour_counts(parts[0])?,
+            });
+        }
+
+        let mut birth = None;
+        let mut survival = None;
+
+        for part in parts {
+            let mut chars = part.chars();
+            let target = match chars.next() {
+                Some('B') | Some('b') => &mut birth,
+                Some('S') | Some('s') => &mut survival,
+                _ => return Err(format!("Rule part '{}' must start with B or S!", part)),
+            };
+
+            if target.is_some() {
+                return Err(format!("Rule '{}' has more than one {} part!", rule, &part[..1]));
+            }
+
+            *target = Some(parse_neighbour_counts(chars.as_str())?);
+        }
+
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Rule { birth, survival }),
+            _ => Err(format!("Rule '{}' must consist of a B and a S part separated by '/'!", rule)),
+        }
+    }
+
+    /// A living cell should die if its number of neighbours is not listed in the survival part.
+    pub fn should_die(&self, number_of_neighbours: usize) -> bool {
+        !lookup(&self.survival, number_of_neighbours)
+    }
+
+    /// At an empty place a new cell should spawn, if its number of neighbours is listed in the
+    /// birth part.
+    pub fn should_spawn(&self, number_of_neighbours: usize) -> bool {
+        lookup(&self.birth, number_of_neighbours)
+    }
+}
+
+fn lookup(counts: &[bool; MAX_NEIGHBOURS + 1], number_of_neighbours: usize) -> bool {
+    number_of_neighbours <= MAX_NEIGHBOURS && counts[number_of_neighbours]
+}
+
+fn parse_neighbour_counts(digits: &str) -> Result<[bool; MAX_NEIGHBOURS + 1], String> {
+    let mut counts = [false; MAX_NEIGHBOURS + 1];
+
+    for digit in digits.chars() {
+        match digit.to_digit(10) {
+            Some(d) if d as usize <= MAX_NEIGHBOURS => counts[d as usize] = true,
+            _ => return Err(format!("Rule contains invalid neighbour count '{}'!", digit)),
+        }
+    }
+
+    Ok(counts)
+}
+
+fn format_neighbour_counts(counts: &[bool; MAX_NEIGHBOURS + 1]) -> String {
+    counts.iter()
+        .enumerate()
+        .filter(|&(_, &set)| set)
+        .map(|(count, _)| count.to_string())
+        .collect()
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "B{}/S{}",
+            format_neighbour_counts(&self.birth),
+            format_neighbour_counts(&self.survival))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn format_conway() {
+        assert_that!(format!("{}", Rule::conway()), is(equal_to(String::from("B3/S23"))));
+    }
+
+    #[test]
+    fn parse_birth_survival_notation() {
+        assert_that!(Rule::parse("B3/S23"), is(equal_to(Ok(Rule::conway()))));
+    }
+
+    #[test]
+    fn parse_survival_birth_notation() {
+        assert_that!(Rule::parse("S23/B3"), is(equal_to(Ok(Rule::conway()))));
+    }
+
+    #[test]
+    fn parse_digits_only_survival_birth_notation() {
+        assert_that!(Rule::parse("23/3"), is(equal_to(Ok(Rule::conway()))));
+        assert_that!(Rule::parse("/2"), is(equal_to(Rule::parse("B2/S"))));
+    }
+
+    #[test]
+    fn parse_lower_case() {
+        assert_that!(Rule::parse("b3/s23"), is(equal_to(Ok(Rule::conway()))));
+    }
+
+    #[test]
+    fn parse_empty_part() {
+        let sut = Rule::parse("B2/S").unwrap();
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from("B2/S"))));
+        assert_that!(sut.should_die(2), is(true));
+        assert_that!(sut.should_spawn(2), is(true));
+    }
+
+    #[test]
+    fn parse_and_format_day_and_night() {
+        let sut = Rule::parse("S34678/B3678").unwrap();
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from("B3678/S34678"))));
+    }
+
+    #[test]
+    fn parse_without_separator() {
+        assert_that!(
+            Rule::parse("B3S23"),
+            is(equal_to(Err(String::from("Rule 'B3S23' must consist of a B and a S part separated by '/'!")))));
+    }
+
+    #[test]
+    fn parse_with_unknown_part() {
+        assert_that!(
+            Rule::parse("B3/X23"),
+            is(equal_to(Err(String::from("Rule part 'X23' must start with B or S!")))));
+    }
+
+    #[test]
+    fn parse_with_duplicate_part() {
+        assert_that!(
+            Rule::parse("B3/B23"),
+            is(equal_to(Err(String::from("Rule 'B3/B23' has more than one B part!")))));
+    }
+
+    #[test]
+    fn parse_with_invalid_neighbour_count() {
+        assert_that!(
+            Rule::parse("B39/S23"),
+            is(equal_to(Err(String::from("Rule contains invalid neighbour count '9'!")))));
+    }
+
+    #[test]
+    fn high_life_spawns_on_six_neighbours() {
+        let sut = Rule::parse("B36/S23").unwrap();
+
+        assert_that!(sut.should_spawn(3), is(true));
+        assert_that!(sut.should_spawn(6), is(true));
+        assert_that!(sut.should_spawn(5), is(false));
+    }
+
+    #[test]
+    fn cell_must_die_if_zero_neighbours() {
+        assert_that!(Rule::conway().should_die(0), is(true));
+    }
+
+    #[test]
+    fn cell_must_die_if_one_neighbours() {
+        assert_that!(Rule::conway().should_die(1), is(true));
+    }
+
+    #[test]
+    fn cell_must_not_die_if_two_neighbours() {
+        assert_that!(Rule::conway().should_die(2), is(false));
+    }
+
+    #[test]
+    fn cell_must_not_die_if_three_neighbours() {
+        assert_that!(Rule::conway().should_die(3), is(false));
+    }
+
+    #[test]
+    fn cell_must_die_if_four_neighbours() {
+        assert_that!(Rule::conway().should_die(4), is(true));
+    }
+
+    #[test]
+    fn cell_must_die_if_five_neighbours() {
+        assert_that!(Rule::conway().should_die(5), is(true));
+    }
+
+    #[test]
+    fn cell_must_die_if_six_neighbours() {
+        assert_that!(Rule::conway().should_die(6), is(true));
+    }
+
+    #[test]
+    fn cell_must_die_if_seven_neighbours() {
+        assert_that!(Rule::conway().should_die(7), is(true));
+    }
+
+    #[test]
+    fn cell_must_die_if_eight_neighbours() {
+        assert_that!(Rule::conway().should_die(8), is(true));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_zero_neighbours() {
+        assert_that!(Rule::conway().should_spawn(0), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_one_neighbours() {
+        assert_that!(Rule::conway().should_spawn(1), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_two_neighbours() {
+        assert_that!(Rule::conway().should_spawn(2), is(false));
+    }
+
+    #[test]
+    fn should_spawn_new_cell_if_three_neighbours() {
+        assert_that!(Rule::conway().should_spawn(3), is(true));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_four_neighbours() {
+        assert_that!(Rule::conway().should_spawn(4), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_five_neighbours() {
+        assert_that!(Rule::conway().should_spawn(5), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_six_neighbours() {
+        assert_that!(Rule::conway().should_spawn(6), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_seven_neighbours() {
+        assert_that!(Rule::conway().should_spawn(7), is(false));
+    }
+
+    #[test]
+    fn should_not_spawn_new_cell_if_eight_neighbours() {
+        assert_that!(Rule::conway().should_spawn(8), is(false));
+    }
+}
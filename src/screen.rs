@@ -1,11 +1,26 @@
-/// Prints the game header.
-pub fn print_header() {
-    println!("Game of Life");
-    println!("============");
-    println!();
+use std::fmt::Write;
+use crate::control::{EDIT_KEY_HELP, KEY_HELP};
+
+/// Writes the game header.
+pub fn print_header(out: &mut String) {
+    out.push_str("Game of Life\n");
+    out.push_str("============\n");
+    out.push('\n');
+}
+
+/// Writes the line describing the keys of the interactive mode.
+pub fn print_key_help(out: &mut String, paused: bool) {
+    let _ = writeln!(out, "{}{}", KEY_HELP, if paused { "  -- paused --" } else { "" });
+    out.push('\n');
+}
+
+/// Writes the line describing the keys of the editor.
+pub fn print_edit_key_help(out: &mut String) {
+    out.push_str(EDIT_KEY_HELP);
+    out.push_str("\n\n");
 }
 
-/// Clears the screen.
-pub fn clear() {
-    print!("\x1b[2J\x1b[1;1H");
+/// Writes the sequence which clears the screen.
+pub fn clear(out: &mut String) {
+    out.push_str("\x1b[2J\x1b[1;1H");
 }
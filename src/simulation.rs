@@ -0,0 +1,240 @@
+use crate::error::Error;
+use crate::generations::Generations;
+use crate::history::History;
+use crate::population::Population;
+use crate::rule::Rule;
+use crate::termination::Termination;
+
+/// This enum describes how a simulation goes on after observers have seen a generation.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Step {
+    /// Continue with the next generation.
+    Continue,
+    /// Start over with the given population, forgetting the history.
+    Restart(Box<Population>),
+    /// End the simulation for the given reason.
+    Stop(Termination),
+}
+
+/// This trait is implemented by everything that watches a simulation: loggers, recorders,
+/// renderers or termination conditions.
+pub trait Observer {
+    /// Called for each generation, beginning with the initial one.
+    ///
+    /// All observers see each generation. The first of them which does not continue decides how
+    /// the simulation goes on.
+    fn on_generation(&mut self, population: &Population) -> Result<Step, Error>;
+
+    /// Called once when the population has become a still life or an oscillator, before the
+    /// simulation ends.
+    fn on_stagnation(&mut self, _population: &Population, _termination: &Termination) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called once when all cells have died, before the simulation ends.
+    fn on_extinction(&mut self, _population: &Population) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// This struct steps a population through its generations and tells observers about them until
+/// the population stops evolving or an observer stops it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Simulation {
+    generations: Generations,
+    history: History,
+    /// How many generations are remembered to detect still lifes and oscillators.
+    window: usize,
+}
+
+impl Simulation {
+    /// Creates a simulation of the given population which remembers the given number of
+    /// generations, but at least one, to detect still lifes and oscillators.
+    pub fn new(population: Population, rule: Rule, window: usize) -> Simulation {
+        Simulation {
+            generations: Generations::new(population, rule),
+            history: History::new(window),
+            window,
+        }
+    }
+
+    /// Get the current generation.
+    pub fn get_population(&self) -> &Population {
+        self.generations.get_population()
+    }
+
+    /// Ends the simulation and returns the current generation.
+    pub fn into_population(self) -> Population {
+        self.generations.into_population()
+    }
+
+    /// Runs the simulation with the given observers and returns why it ended.
+    ///
+    /// This may run endless, if no observer stops it and the population never stops evolving.
+    pub fn run(&mut self, observers: &mut [&mut dyn Observer]) -> Result<Termination, Error> {
+        loop {
+            let mut step = Step::Continue;
+
+            for observer in observers.iter_mut() {
+                let next = observer.on_generation(self.get_population())?;
+
+                if let Step::Continue = step {
+                    step = next;
+                }
+            }
+
+            match step {
+                Step::Continue => {},
+                Step::Restart(population) => {
+                    self.generations = Generations::new(*population, self.generations.get_rule().clone());
+                    self.history = History::new(self.window);
+                    continue;
+                },
+                Step::Stop(termination) => return Ok(termination),
+            }
+
+            let population = self.generations.get_population();
+
+            if let Some(termination) = self.history.record(population) {
+                for observer in observers.iter_mut() {
+                    match termination {
+                        Termination::Extinct { .. } => observer.on_extinction(population)?,
+                        _ => observer.on_stagnation(population, &termination)?,
+                    }
+                }
+
+                return Ok(termination);
+            }
+
+            self.generations.advance();
+        }
+    }
+}
+
+/// This struct is an observer which stops the simulation at the given generation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GenerationLimit {
+    generations: usize,
+}
+
+impl GenerationLimit {
+    /// Creates an observer stopping at the given generation.
+    pub fn new(generations: usize) -> GenerationLimit {
+        GenerationLimit { generations }
+    }
+}
+
+impl Observer for GenerationLimit {
+    fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+        let generation = population.get_status().get_iteration();
+
+        if generation >= self.generations {
+            Ok(Step::Stop(Termination::GenerationLimit { generation }))
+        } else {
+            Ok(Step::Continue)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::population::create_blinker;
+    use crate::cell::Cell;
+    use crate::place::Place;
+    use hamcrest::prelude::*;
+
+    /// Remembers which generations and events it has seen.
+    #[derive(Default)]
+    struct Log {
+        generations: Vec<usize>,
+        stagnations: Vec<Termination>,
+        extinctions: usize,
+    }
+
+    impl Observer for Log {
+        fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+            self.generations.push(population.get_status().get_iteration());
+            Ok(Step::Continue)
+        }
+
+        fn on_stagnation(&mut self, _population: &Population, termination: &Termination) -> Result<(), Error> {
+            self.stagnations.push(termination.clone());
+            Ok(())
+        }
+
+        fn on_extinction(&mut self, _population: &Population) -> Result<(), Error> {
+            self.extinctions += 1;
+            Ok(())
+        }
+    }
+
+    /// Restarts with the given population once the given generation is reached.
+    struct Restarter {
+        generation: usize,
+        population: Option<Population>,
+    }
+
+    impl Observer for Restarter {
+        fn on_generation(&mut self, population: &Population) -> Result<Step, Error> {
+            match self.population.take() {
+                Some(restart) if population.get_status().get_iteration() == self.generation => Ok(Step::Restart(Box::new(restart))),
+                restart => {
+                    self.population = restart;
+                    Ok(Step::Continue)
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn run_until_generation_limit() {
+        let mut log = Log::default();
+        let mut limit = GenerationLimit::new(3);
+        let mut sut = Simulation::new(create_blinker(), Rule::conway(), 1);
+
+        let termination = sut.run(&mut [&mut log, &mut limit]);
+
+        assert_that!(termination, is(equal_to(Ok(Termination::GenerationLimit { generation: 3 }))));
+        assert_that!(log.generations, is(equal_to(vec![0, 1, 2, 3])));
+        assert_that!(sut.get_population().get_status().get_iteration(), is(equal_to(3)));
+    }
+
+    #[test]
+    fn run_tells_about_stagnation() {
+        let mut log = Log::default();
+        let mut sut = Simulation::new(create_blinker(), Rule::conway(), 4);
+
+        let termination = sut.run(&mut [&mut log]);
+        let expected = Termination::Oscillator { period: 2, generation: 0 };
+
+        assert_that!(termination, is(equal_to(Ok(expected.clone()))));
+        assert_that!(log.stagnations, is(equal_to(vec![expected])));
+        assert_that!(log.extinctions, is(equal_to(0)));
+    }
+
+    #[test]
+    fn run_tells_about_extinction() {
+        let mut log = Log::default();
+        let mut sut = Simulation::new(Population::new(3, 3, vec![Cell::new(Place::new(1, 1))]).unwrap(), Rule::conway(), 4);
+
+        let termination = sut.run(&mut [&mut log]);
+
+        assert_that!(termination, is(equal_to(Ok(Termination::Extinct { generation: 1 }))));
+        assert_that!(log.extinctions, is(equal_to(1)));
+        assert_that!(log.stagnations.len(), is(equal_to(0)));
+    }
+
+    #[test]
+    fn run_restarts_with_new_population() {
+        let mut log = Log::default();
+        let mut restarter = Restarter { generation: 1, population: Some(create_blinker()) };
+        let mut limit = GenerationLimit::new(2);
+        let mut sut = Simulation::new(create_blinker(), Rule::conway(), 4);
+
+        let termination = sut.run(&mut [&mut restarter, &mut log, &mut limit]);
+
+        assert_that!(termination, is(equal_to(Ok(Termination::GenerationLimit { generation: 2 }))));
+        assert_that!(log.generations, is(equal_to(vec![0, 1, 0, 1, 2])));
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::age::AGE_CLASSES;
 
 /// This struct describes the status of a population.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,12 +12,19 @@ pub struct Status {
     born: usize,
     /// How many cells were died in comparison to the previous iteration.
     died: usize,
+    /// Number of living cells per age class, from young to old.
+    ages: [usize; AGE_CLASSES],
 }
 
 impl Status {
     /// Creates a new status.
     pub fn new(iteration: usize, cells: usize, born: usize, died: usize) -> Status {
-        Status { iteration, cells, born, died }
+        Status { iteration, cells, born, died, ages: [0; AGE_CLASSES] }
+    }
+
+    /// Creates a copy of this status with the given number of living cells per age class.
+    pub fn with_ages(self, ages: [usize; AGE_CLASSES]) -> Status {
+        Status { ages, ..self }
     }
 
     /// Get the iteration count.
@@ -32,6 +40,7 @@ impl Status {
             cells: self.get_cells(),
             born: self.get_born(),
             died: self.get_died(),
+            ages: self.ages,
         }
     }
 
@@ -53,6 +62,7 @@ impl Status {
             cells: self.get_cells() + 1,
             born: self.get_born() + 1,
             died: self.get_died(),
+            ages: self.ages,
         }
     }
 
@@ -61,6 +71,11 @@ impl Status {
         self.died
     }
 
+    /// Get the number of living cells per age class, from young to old.
+    pub fn get_ages(&self) -> &[usize; AGE_CLASSES] {
+        &self.ages
+    }
+
     /// This method increases the died property by one and decreases the cells property by one and
     /// returns a new status. The original status will be unchanged.
     pub fn inc_died(&self) -> Status {
@@ -69,25 +84,24 @@ impl Status {
             cells: self.get_cells() - 1,
             born: self.get_born(),
             died: self.get_died() + 1,
+            ages: self.ages,
         }
     }
-
-    /// Determines if this population has changed to the other.
-    ///A population is considered changed if either born or died cell number is different.
-    pub fn is_population_unchanged(&self, other: Status) -> bool {
-        self.get_died() == other.get_died() && self.get_born() == other.get_born()
-    }
 }
 
 impl fmt::Display for Status {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "Iteration: {:5}, Cells:  {:5}, Born:  {:5}, Died:   {:5}",
+            "Iteration: {:5}, Cells:  {:5}, Born:  {:5}, Died:   {:5}, Ages (new/young/stable/ancient): {}/{}/{}/{}",
             self.iteration,
             self.cells,
             self.born,
-            self.died)
+            self.died,
+            self.ages[0],
+            self.ages[1],
+            self.ages[2],
+            self.ages[3])
     }
 }
 
@@ -121,35 +135,11 @@ mod tests {
 
     #[test]
     fn fmt() {
-        let sut = Status::new(42, 23, 5, 3);
+        let sut = Status::new(42, 23, 5, 3).with_ages([4, 10, 8, 1]);
 
         assert_that!(
             format!("{}", sut),
-            is(equal_to(String::from("Iteration:    42, Cells:     23, Born:      5, Died:       3"))));
-    }
-
-    #[test]
-    fn is_population_unchanged_died_and_born_equal() {
-        assert_that!(
-            Status::new(0, 0, 1, 2).is_population_unchanged(Status::new(0, 0, 1, 2)),
-            is(equal_to(true))
-        );
-    }
-
-    #[test]
-    fn is_population_unchanged_died_different() {
-        assert_that!(
-            Status::new(0, 0, 1, 2).is_population_unchanged(Status::new(0, 0, 1, 3)),
-            is(equal_to(false))
-        );
-    }
-
-    #[test]
-    fn is_population_unchanged_born_different() {
-        assert_that!(
-            Status::new(0, 0, 1, 2).is_population_unchanged(Status::new(0, 0, 3, 2)),
-            is(equal_to(false
-            ))
-        );
+            is(equal_to(String::from(
+                "Iteration:    42, Cells:     23, Born:      5, Died:       3, Ages (new/young/stable/ancient): 4/10/8/1"))));
     }
 }
\ No newline at end of file
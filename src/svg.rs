@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::Path;
+use crate::error::Error;
+use crate::image::Color;
+use crate::place::Place;
+use crate::population::Population;
+use crate::rule::Rule;
+
+/// Default size of a place in pixels.
+pub const DEFAULT_SCALE: usize = 10;
+
+/// This struct describes how a population is drawn as SVG.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SvgStyle {
+    /// Size of a place in pixels.
+    scale: usize,
+    /// Whether places are separated by grid lines.
+    grid: bool,
+    /// Whether dying cells are drawn in their own colour instead of being left out.
+    dying: bool,
+    alive_color: Color,
+    dying_color: Color,
+    background_color: Color,
+    grid_color: Color,
+}
+
+impl SvgStyle {
+    /// Creates a style with black living cells on white, without grid and dying cells.
+    pub fn new() -> SvgStyle {
+        SvgStyle {
+            scale: DEFAULT_SCALE,
+            grid: false,
+            dying: false,
+            alive_color: (0, 0, 0),
+            dying_color: (160, 160, 160),
+            background_color: (255, 255, 255),
+            grid_color: (208, 208, 208),
+        }
+    }
+
+    /// Creates a copy of this style with the given size of a place in pixels, but at least one.
+    pub fn with_scale(self, scale: usize) -> SvgStyle {
+        SvgStyle { scale: scale.max(1), ..self }
+    }
+
+    /// Creates a copy of this style which separates places by grid lines.
+    pub fn with_grid(self) -> SvgStyle {
+        SvgStyle { grid: true, ..self }
+    }
+
+    /// Creates a copy of this style which draws dying cells, too.
+    pub fn with_dying(self) -> SvgStyle {
+        SvgStyle { dying: true, ..self }
+    }
+
+    /// Creates a copy of this style with the given colours of living cells, dying cells, the
+    /// background and grid lines.
+    pub fn with_colors(self, alive: Color, dying: Color, background: Color, grid: Color) -> SvgStyle {
+        SvgStyle {
+            alive_color: alive,
+            dying_color: dying,
+            background_color: background,
+            grid_color: grid,
+            ..self
+        }
+    }
+}
+
+impl Default for SvgStyle {
+    fn default() -> SvgStyle {
+        SvgStyle::new()
+    }
+}
+
+/// Whether the given file name has the SVG extension.
+pub fn is_svg(file: &str) -> bool {
+    Path::new(file)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("svg"))
+}
+
+/// Writes the given population as SVG.
+///
+/// The drawing uses one unit per place. Horizontally adjacent cells are merged into one
+/// rectangle to keep the file small. The status and the rule are embedded as metadata.
+pub fn write(population: &Population, rule: &Rule, style: &SvgStyle) -> String {
+    let width = population.get_size().get_width();
+    let height = population.get_size().get_height();
+    let status = population.get_status();
+    let mut svg = String::new();
+
+    svg.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width * style.scale,
+        height * style.scale,
+        width,
+        height));
+    svg.push_str(&format!("  <title>Generation {}</title>\n", status.get_iteration()));
+    svg.push_str(&format!("  <desc>{}, Rule: {}</desc>\n", escape(&status.to_string()), escape(&rule.to_string())));
+    svg.push_str(&format!(
+        "  <metadata data-iteration=\"{}\" data-cells=\"{}\" data-born=\"{}\" data-died=\"{}\" data-rule=\"{}\"/>\n",
+        status.get_iteration(),
+        status.get_cells(),
+        status.get_born(),
+        status.get_died(),
+        escape(&rule.to_string())));
+    svg.push_str(&format!(
+        "  <rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        width,
+        height,
+        format_color(style.background_color)));
+
+    if style.dying {
+        write_runs(&mut svg, population, true, style.dying_color);
+    }
+
+    write_runs(&mut svg, population, false, style.alive_color);
+
+    if style.grid {
+        write_grid(&mut svg, width, height, style.grid_color);
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Saves the given population as SVG.
+pub fn save(file: &str, population: &Population, rule: &Rule, style: &SvgStyle) -> Result<(), Error> {
+    fs::write(file, write(population, rule, style))
+        .map_err(|err| Error::io(format!("write SVG file '{}'", file), err))
+}
+
+/// Writes a group of rectangles, one per run of horizontally adjacent dead or living cells.
+fn write_runs(svg: &mut String, population: &Population, dead: bool, color: Color) {
+    let width = population.get_size().get_width();
+    let mut rects = String::new();
+
+    for y in 0..population.get_size().get_height() {
+        let mut start: Option<usize> = None;
+
+        for x in 0..=width {
+            let is_part = x < width && population.get_cell(&Place::new(x, y))
+                .is_some_and(|cell| cell.is_dead() == dead);
+
+            match (is_part, start) {
+                (true, None) => start = Some(x),
+                (false, Some(first)) => {
+                    rects.push_str(&format!(
+                        "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\"/>\n",
+                        first,
+                        y,
+                        x - first));
+                    start = None;
+                },
+                _ => {},
+            }
+        }
+    }
+
+    if !rects.is_empty() {
+        let class = if dead { "dying" } else { "alive" };
+        svg.push_str(&format!("  <g class=\"{}\" fill=\"{}\">\n{}  </g>\n", class, format_color(color), rects));
+    }
+}
+
+/// Writes the lines between and around all places as one path.
+fn write_grid(svg: &mut String, width: usize, height: usize, color: Color) {
+    let mut path = String::new();
+
+    for x in 0..=width {
+        path.push_str(&format!("M{} 0V{}", x, height));
+    }
+
+    for y in 0..=height {
+        path.push_str(&format!("M0 {}H{}", y, width));
+    }
+
+    svg.push_str(&format!(
+        "  <path d=\"{}\" stroke=\"{}\" stroke-width=\"0.05\" fill=\"none\"/>\n",
+        path,
+        format_color(color)));
+}
+
+/// Formats a colour as hexadecimal CSS colour.
+fn format_color((red, green, blue): Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", red, green, blue)
+}
+
+/// Escapes the characters which have a meaning in XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use hamcrest::prelude::*;
+
+    fn create_population() -> Population {
+        Population::new(4, 2, vec![
+            Cell::new(Place::new(0, 0)),
+            Cell::new(Place::new(1, 0)),
+            Cell::new(Place::new(3, 0)),
+            Cell::new(Place::new(2, 1)).kill(),
+        ]).unwrap()
+    }
+
+    #[test]
+    fn is_svg_by_extension() {
+        assert_that!(is_svg("generation.SVG"), is(true));
+        assert_that!(is_svg("generation.rle"), is(false));
+        assert_that!(is_svg("svg"), is(false));
+    }
+
+    #[test]
+    fn write_merges_runs_of_living_cells() {
+        let svg = write(&create_population(), &Rule::conway(), &SvgStyle::new());
+        let expected = concat!(
+            "  <g class=\"alive\" fill=\"#000000\">\n",
+            "    <rect x=\"0\" y=\"0\" width=\"2\" height=\"1\"/>\n",
+            "    <rect x=\"3\" y=\"0\" width=\"1\" height=\"1\"/>\n",
+            "  </g>\n");
+
+        assert_that!(svg.contains(expected), is(true));
+        assert_that!(svg.contains("class=\"dying\""), is(false));
+        assert_that!(svg.contains("<path"), is(false));
+    }
+
+    #[test]
+    fn write_with_scale() {
+        let svg = write(&create_population(), &Rule::conway(), &SvgStyle::new().with_scale(5));
+
+        assert_that!(svg.contains("width=\"20\" height=\"10\" viewBox=\"0 0 4 2\""), is(true));
+    }
+
+    #[test]
+    fn write_dying_cells() {
+        let svg = write(&create_population(), &Rule::conway(), &SvgStyle::new().with_dying());
+        let expected = concat!(
+            "  <g class=\"dying\" fill=\"#a0a0a0\">\n",
+            "    <rect x=\"2\" y=\"1\" width=\"1\" height=\"1\"/>\n",
+            "  </g>\n");
+
+        assert_that!(svg.contains(expected), is(true));
+    }
+
+    #[test]
+    fn write_grid_lines() {
+        let svg = write(&create_population(), &Rule::conway(), &SvgStyle::new().with_grid());
+
+        assert_that!(
+            svg.contains("<path d=\"M0 0V2M1 0V2M2 0V2M3 0V2M4 0V2M0 0H4M0 1H4M0 2H4\" stroke=\"#d0d0d0\""),
+            is(true));
+    }
+
+    #[test]
+    fn write_embeds_status_and_rule() {
+        let population = create_population();
+        let svg = write(&population, &Rule::parse("B36/S23").unwrap(), &SvgStyle::new());
+
+        assert_that!(svg.contains("<title>Generation 0</title>"), is(true));
+        assert_that!(
+            svg.contains(&format!("<desc>{}, Rule: B36/S23</desc>", population.get_status())),
+            is(true));
+        assert_that!(
+            svg.contains("<metadata data-iteration=\"0\" data-cells=\"3\" data-born=\"0\" data-died=\"0\" data-rule=\"B36/S23\"/>"),
+            is(true));
+    }
+
+    #[test]
+    fn escape_xml() {
+        assert_that!(escape("<a & \"b\">"), is(equal_to(String::from("&lt;a &amp; &quot;b&quot;&gt;"))));
+    }
+}
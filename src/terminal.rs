@@ -0,0 +1,101 @@
+use std::io::{self, Stdout, Write};
+use termion::async_stdin;
+use termion::cursor;
+use termion::event::Key;
+use termion::input::{Keys, TermRead};
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::{ToAlternateScreen, ToMainScreen};
+use termion::AsyncReader;
+use crate::control::Command;
+use crate::error::Error;
+use crate::renderer::Renderer;
+use crate::screen::clear;
+
+/// This struct gives access to the terminal in raw mode, so single key presses can be read
+/// without waiting for the enter key.
+///
+/// The terminal is restored to its previous mode when this struct is dropped.
+pub struct Terminal {
+    output: RawTerminal<Stdout>,
+    keys: Keys<AsyncReader>,
+    /// Only given if the frames are drawn flicker-free onto the alternate screen.
+    renderer: Option<Renderer>,
+}
+
+impl Terminal {
+    /// Switches the terminal into raw mode.
+    pub fn new() -> Result<Terminal, Error> {
+        let output = io::stdout()
+            .into_raw_mode()
+            .map_err(|err| Error::io(String::from("switch terminal into raw mode"), err))?;
+
+        Ok(Terminal { output, keys: async_stdin().keys(), renderer: None })
+    }
+
+    /// Switches to the alternate screen with hidden cursor, where frames are drawn by only
+    /// rewriting the changed cells.
+    ///
+    /// The main screen and the cursor are restored when this struct is dropped.
+    pub fn with_alternate_screen(mut self) -> Terminal {
+        self.print(&format!("{}{}", ToAlternateScreen, cursor::Hide));
+        self.renderer = Some(Renderer::new());
+        self
+    }
+
+    /// Returns the command of the next pressed key without blocking.
+    ///
+    /// Keys without a command are skipped.
+    pub fn poll_command(&mut self) -> Option<Command> {
+        while let Some(key) = self.poll_key() {
+            if let Some(command) = Command::from_key(&key) {
+                return Some(command);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the next pressed key without blocking.
+    pub fn poll_key(&mut self) -> Option<Key> {
+        match self.keys.next() {
+            Some(Ok(key)) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Draws the given frame over the previous one.
+    ///
+    /// On the alternate screen only the changed cells are rewritten, else the screen is cleared
+    /// and the whole frame printed.
+    pub fn draw(&mut self, frame: &str) {
+        let output = match self.renderer.as_mut() {
+            Some(renderer) => renderer.render(frame),
+            None => {
+                let mut output = String::new();
+                clear(&mut output);
+                output.push_str(frame);
+                output
+            },
+        };
+
+        self.print(&output);
+    }
+
+    /// Prints the given text.
+    ///
+    /// In raw mode a line feed does not return the cursor to the line start, so a carriage return
+    /// is added to each line.
+    pub fn print(&mut self, text: &str) {
+        // There is nothing sensible to do if the terminal is gone.
+        let _ = write!(self.output, "{}", text.replace('\n', "\r\n"));
+        let _ = self.output.flush();
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if self.renderer.is_some() {
+            self.print(&format!("{}{}", ToMainScreen, cursor::Show));
+        }
+    }
+}
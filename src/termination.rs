@@ -0,0 +1,130 @@
+use std::fmt;
+
+/// This enum describes why a game has ended.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Termination {
+    /// The configured maximum number of generations was reached.
+    GenerationLimit {
+        /// The last generation.
+        generation: usize,
+    },
+    /// All cells have died.
+    Extinct {
+        /// The first generation without living cells.
+        generation: usize,
+    },
+    /// The population does not change anymore.
+    StillLife {
+        /// The first generation of the unchanging population.
+        generation: usize,
+    },
+    /// The population repeats itself periodically.
+    Oscillator {
+        /// Number of generations after which the population repeats.
+        period: usize,
+        /// The first generation of the cycle.
+        generation: usize,
+    },
+    /// The user has quit the interactive game.
+    Quit {
+        /// The generation shown when quitting.
+        generation: usize,
+    },
+}
+
+impl Termination {
+    /// The exit code of the process for this termination reason.
+    ///
+    /// The codes from 64 on are left out because they signal errors.
+    pub fn get_exit_code(&self) -> i32 {
+        match *self {
+            Termination::GenerationLimit { .. } => 0,
+            Termination::Extinct { .. } => 2,
+            Termination::StillLife { .. } => 3,
+            Termination::Oscillator { .. } => 4,
+            Termination::Quit { .. } => 5,
+        }
+    }
+}
+
+impl fmt::Display for Termination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Termination::GenerationLimit { generation } =>
+                write!(f, "Generation limit of {} generations reached.", generation),
+            Termination::Extinct { generation } =>
+                write!(f, "Population is extinct since generation {}.", generation),
+            Termination::StillLife { generation } =>
+                write!(f, "Population is a still life since generation {}.", generation),
+            Termination::Oscillator { period, generation } =>
+                write!(f, "Population is an oscillator of period {} since generation {}.", period, generation),
+            Termination::Quit { generation } =>
+                write!(f, "Game quit by user in generation {}.", generation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn format_generation_limit() {
+        assert_that!(
+            format!("{}", Termination::GenerationLimit { generation: 100 }),
+            is(equal_to(String::from("Generation limit of 100 generations reached."))));
+    }
+
+    #[test]
+    fn exit_codes() {
+        assert_that!(Termination::GenerationLimit { generation: 1 }.get_exit_code(), is(equal_to(0)));
+        assert_that!(Termination::Extinct { generation: 1 }.get_exit_code(), is(equal_to(2)));
+        assert_that!(Termination::StillLife { generation: 1 }.get_exit_code(), is(equal_to(3)));
+        assert_that!(Termination::Oscillator { period: 2, generation: 1 }.get_exit_code(), is(equal_to(4)));
+        assert_that!(Termination::Quit { generation: 1 }.get_exit_code(), is(equal_to(5)));
+    }
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let mut codes: Vec<i32> = [
+            Termination::GenerationLimit { generation: 1 },
+            Termination::Extinct { generation: 1 },
+            Termination::StillLife { generation: 1 },
+            Termination::Oscillator { period: 2, generation: 1 },
+            Termination::Quit { generation: 1 },
+        ].iter().map(Termination::get_exit_code).collect();
+        codes.sort();
+        codes.dedup();
+
+        assert_that!(codes.len(), is(equal_to(5)));
+    }
+
+    #[test]
+    fn format_extinct() {
+        assert_that!(
+            format!("{}", Termination::Extinct { generation: 5 }),
+            is(equal_to(String::from("Population is extinct since generation 5."))));
+    }
+
+    #[test]
+    fn format_still_life() {
+        assert_that!(
+            format!("{}", Termination::StillLife { generation: 7 }),
+            is(equal_to(String::from("Population is a still life since generation 7."))));
+    }
+
+    #[test]
+    fn format_oscillator() {
+        assert_that!(
+            format!("{}", Termination::Oscillator { period: 2, generation: 3 }),
+            is(equal_to(String::from("Population is an oscillator of period 2 since generation 3."))));
+    }
+
+    #[test]
+    fn format_quit() {
+        assert_that!(
+            format!("{}", Termination::Quit { generation: 9 }),
+            is(equal_to(String::from("Game quit by user in generation 9."))));
+    }
+}
@@ -0,0 +1,272 @@
+use unicode_width::UnicodeWidthStr;
+use crate::cell::Cell;
+
+/// Number of glyphs dying cells fade through.
+const FADE_STAGES: usize = 3;
+
+/// This struct describes how the cells and the frame of a population are drawn.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Theme {
+    /// Glyph of living cells.
+    alive: String,
+    /// Glyphs of dying cells, from just died to almost gone.
+    dying: [String; FADE_STAGES],
+    /// Glyph of places without cell.
+    empty: String,
+    /// Whether a frame is drawn around the population.
+    border: bool,
+    /// Glyph of the corners of the frame.
+    corner: String,
+    /// Glyph of the upper and lower side of the frame.
+    horizontal: String,
+    /// Glyph of the left and right side of the frame.
+    vertical: String,
+}
+
+impl Theme {
+    fn new(alive: &str, dying: [&str; FADE_STAGES], empty: &str) -> Theme {
+        Theme {
+            alive: String::from(alive),
+            dying: [String::from(dying[0]), String::from(dying[1]), String::from(dying[2])],
+            empty: String::from(empty),
+            border: true,
+            corner: String::from("+"),
+            horizontal: String::from("-"),
+            vertical: String::from("|"),
+        }
+    }
+
+    /// Suns for living and dying cells, the default theme.
+    pub fn sun() -> Theme {
+        Theme::new("☀", ["☼", "•", "·"], " ")
+    }
+
+    /// Only ASCII characters, which render in any font and are easy to diff.
+    pub fn ascii() -> Theme {
+        Theme::new("#", [".", ".", "."], " ")
+    }
+
+    /// Block elements getting lighter as dying cells fade.
+    pub fn block() -> Theme {
+        Theme::new("█", ["▓", "▒", "░"], " ")
+    }
+
+    /// Moon phases waning as dying cells fade, each two columns wide.
+    pub fn emoji() -> Theme {
+        Theme::new("🌕", ["🌖", "🌗", "🌘"], "  ")
+    }
+
+    /// Parses a theme from its name (`sun`, `ascii`, `block` or `emoji`).
+    pub fn parse(name: &str) -> Result<Theme, String> {
+        match name.trim().to_lowercase().as_str() {
+            "sun" => Ok(Theme::sun()),
+            "ascii" => Ok(Theme::ascii()),
+            "block" => Ok(Theme::block()),
+            "emoji" => Ok(Theme::emoji()),
+            _ => Err(format!("Unknown theme '{}'! Expected 'sun', 'ascii', 'block' or 'emoji'.", name)),
+        }
+    }
+
+    /// Parses a custom theme from the comma separated glyphs of living, dying and empty places.
+    ///
+    /// All glyphs must be equally wide.
+    pub fn parse_glyphs(glyphs: &str) -> Result<Theme, String> {
+        let glyphs: Vec<&str> = glyphs.split(',').collect();
+
+        if glyphs.len() != 3 {
+            return Err(format!(
+                "Expected three comma separated glyphs for living, dying and empty places, but got {}!",
+                glyphs.len()));
+        }
+
+        let width = glyphs[0].width();
+
+        if width == 0 || glyphs.iter().any(|glyph| glyph.width() != width) {
+            return Err(String::from("All glyphs must be equally wide and not empty!"));
+        }
+
+        Ok(Theme::new(glyphs[0], [glyphs[1], glyphs[1], glyphs[1]], glyphs[2]))
+    }
+
+    /// Creates a copy of this theme which draws no frame around the population.
+    pub fn without_border(self) -> Theme {
+        Theme { border: false, ..self }
+    }
+
+    /// Creates a copy of this theme whose frame is drawn with the given glyphs of the corners, the
+    /// upper and lower side and the left and right side, each one column wide.
+    pub fn with_frame(self, corner: &str, horizontal: &str, vertical: &str) -> Theme {
+        Theme {
+            corner: String::from(corner),
+            horizontal: String::from(horizontal),
+            vertical: String::from(vertical),
+            ..self
+        }
+    }
+
+    /// Whether a frame is drawn around the population.
+    pub fn has_border(&self) -> bool {
+        self.border
+    }
+
+    /// Get the glyph of the given cell, if dead cells are shown for the given number of
+    /// generations.
+    ///
+    /// The longer a cell is dead, the fainter it is shown. The fade stages are spread evenly over
+    /// the trail, so the first stage is shown right after dying and the last one at the end of the
+    /// trail.
+    pub fn get_glyph(&self, cell: &Cell, trail: usize) -> &str {
+        if !cell.is_dead() {
+            return &self.alive;
+        }
+
+        // The decay of the last generation on the trail.
+        let last = trail.saturating_sub(1);
+
+        if last == 0 {
+            return &self.dying[0];
+        }
+
+        // Rounded to the nearest stage.
+        let stage = (cell.get_decay().min(last) * (FADE_STAGES - 1) + last / 2) / last;
+
+        &self.dying[stage]
+    }
+
+    /// Get the glyph of living cells.
+    pub fn get_alive(&self) -> &str {
+        &self.alive
+    }
+
+    /// Get the glyph of places without cell.
+    pub fn get_empty(&self) -> &str {
+        &self.empty
+    }
+
+    /// Number of terminal columns each glyph takes.
+    pub fn get_width(&self) -> usize {
+        self.alive.width()
+    }
+
+    /// Generates the upper or lower frame line around the given number of glyphs.
+    pub fn generate_line(&self, glyphs: usize) -> String {
+        self.generate_line_for_columns(glyphs * self.get_width())
+    }
+
+    /// Generates the upper or lower frame line around the given number of terminal columns.
+    pub fn generate_line_for_columns(&self, columns: usize) -> String {
+        format!("{}{}{}", self.corner, self.horizontal.repeat(columns), self.corner)
+    }
+
+    /// Get the glyph of the left and right side of the frame.
+    pub fn get_side(&self) -> &str {
+        &self.vertical
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::sun()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::place::Place;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn parse_known_themes() {
+        assert_that!(Theme::parse("sun"), is(equal_to(Ok(Theme::sun()))));
+        assert_that!(Theme::parse("ASCII"), is(equal_to(Ok(Theme::ascii()))));
+        assert_that!(Theme::parse("block"), is(equal_to(Ok(Theme::block()))));
+        assert_that!(Theme::parse("emoji"), is(equal_to(Ok(Theme::emoji()))));
+    }
+
+    #[test]
+    fn parse_unknown_theme() {
+        assert_that!(
+            Theme::parse("fancy"),
+            is(equal_to(Err(String::from("Unknown theme 'fancy'! Expected 'sun', 'ascii', 'block' or 'emoji'.")))));
+    }
+
+    #[test]
+    fn parse_custom_glyphs() {
+        let sut = Theme::parse_glyphs("O,x,-").unwrap();
+        let cell = Cell::new(Place::new(0, 0));
+
+        assert_that!(sut.get_glyph(&cell, 1), is(equal_to("O")));
+        assert_that!(sut.get_glyph(&cell.kill().fade(), 2), is(equal_to("x")));
+        assert_that!(sut.get_empty(), is(equal_to("-")));
+    }
+
+    #[test]
+    fn parse_custom_glyphs_with_wrong_count() {
+        assert_that!(
+            Theme::parse_glyphs("O,x"),
+            is(equal_to(Err(String::from(
+                "Expected three comma separated glyphs for living, dying and empty places, but got 2!")))));
+    }
+
+    #[test]
+    fn parse_custom_glyphs_with_different_widths() {
+        assert_that!(
+            Theme::parse_glyphs("🌕,x, "),
+            is(equal_to(Err(String::from("All glyphs must be equally wide and not empty!")))));
+    }
+
+    fn fade_glyphs(trail: usize) -> Vec<String> {
+        let mut cell = Cell::new(Place::new(0, 0)).kill();
+        let mut glyphs = Vec::new();
+
+        for _ in 0..trail {
+            glyphs.push(String::from(Theme::block().get_glyph(&cell, trail)));
+            cell = cell.fade();
+        }
+
+        glyphs
+    }
+
+    #[test]
+    fn get_glyph_fades_dying_cells_along_trail() {
+        assert_that!(fade_glyphs(1), is(equal_to(vec![String::from("▓")])));
+        assert_that!(fade_glyphs(2), is(equal_to(vec![String::from("▓"), String::from("░")])));
+        assert_that!(
+            fade_glyphs(3),
+            is(equal_to(vec![String::from("▓"), String::from("▒"), String::from("░")])));
+    }
+
+    #[test]
+    fn get_glyph_spreads_stages_over_long_trail() {
+        let glyphs = fade_glyphs(30);
+
+        assert_that!(glyphs.iter().filter(|glyph| *glyph == "▓").count(), is(equal_to(8)));
+        assert_that!(glyphs.iter().filter(|glyph| *glyph == "▒").count(), is(equal_to(14)));
+        assert_that!(glyphs.iter().filter(|glyph| *glyph == "░").count(), is(equal_to(8)));
+    }
+
+    #[test]
+    fn generate_line_for_columns_zero_width() {
+        assert_that!(Theme::sun().generate_line_for_columns(0), is(equal_to(String::from("++"))));
+    }
+
+    #[test]
+    fn generate_line_for_columns_some_width() {
+        assert_that!(Theme::sun().generate_line_for_columns(5), is(equal_to(String::from("+-----+"))));
+    }
+
+    #[test]
+    fn with_frame_changes_lines_and_sides() {
+        let sut = Theme::block().with_frame("#", "=", "!");
+
+        assert_that!(sut.generate_line(3), is(equal_to(String::from("#===#"))));
+        assert_that!(sut.get_side(), is(equal_to("!")));
+    }
+
+    #[test]
+    fn generate_line_for_wide_glyphs() {
+        assert_that!(Theme::ascii().generate_line(3), is(equal_to(String::from("+---+"))));
+        assert_that!(Theme::emoji().generate_line(3), is(equal_to(String::from("+------+"))));
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+use crate::dimension::Dimension;
+use crate::place::Place;
+
+/// This enum describes how the edges of the population space are connected.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Topology {
+    /// The space is a hard-edged box: Places outside of it are always empty.
+    Plane,
+    /// The space wraps around on both axes: The left edge touches the right one and the top edge
+    /// touches the bottom one.
+    Torus,
+}
+
+impl Topology {
+    /// Parses a topology from its name (`plane` or `torus`).
+    pub fn parse(name: &str) -> Result<Topology, String> {
+        match name.trim().to_lowercase().as_str() {
+            "plane" => Ok(Topology::Plane),
+            "torus" => Ok(Topology::Torus),
+            _ => Err(format!("Unknown topology '{}'! Expected 'plane' or 'torus'.", name)),
+        }
+    }
+
+    /// Calculates the places surrounding the given place inside of the given dimension.
+    ///
+    /// The place itself is never part of its neighbours and each neighbour is only returned once,
+    /// even if the space is so small that wrapping around reaches the same place multiple times.
+    pub fn neighbours(&self, position: &Place, size: &Dimension) -> Vec<Place> {
+        let mut neighbours: Vec<Place> = Vec::with_capacity(8);
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+
+                let x = self.translate(position.get_x(), dx, size.get_width());
+                let y = self.translate(position.get_y(), dy, size.get_height());
+
+                if let (Some(x), Some(y)) = (x, y) {
+                    let neighbour = Place::new(x, y);
+
+                    if neighbour != *position && !neighbours.contains(&neighbour) {
+                        neighbours.push(neighbour);
+                    }
+                }
+            }
+        }
+
+        neighbours
+    }
+
+    /// Moves a coordinate by the given offset along an axis of the given length.
+    fn translate(&self, coordinate: usize, offset: isize, length: usize) -> Option<usize> {
+        let moved = coordinate as isize + offset;
+
+        match *self {
+            Topology::Plane => {
+                if moved < 0 || moved >= length as isize {
+                    None
+                } else {
+                    Some(moved as usize)
+                }
+            },
+            // An empty axis has no places to wrap around to.
+            Topology::Torus if length == 0 => None,
+            Topology::Torus => Some(moved.rem_euclid(length as isize) as usize),
+        }
+    }
+}
+
+impl fmt::Display for Topology {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Topology::Plane => write!(f, "plane"),
+            Topology::Torus => write!(f, "torus"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn format_topology() {
+        assert_that!(format!("{}", Topology::Plane), is(equal_to(String::from("plane"))));
+        assert_that!(format!("{}", Topology::Torus), is(equal_to(String::from("torus"))));
+    }
+
+    #[test]
+    fn parse_known_topologies() {
+        assert_that!(Topology::parse("plane"), is(equal_to(Ok(Topology::Plane))));
+        assert_that!(Topology::parse("Torus"), is(equal_to(Ok(Topology::Torus))));
+    }
+
+    #[test]
+    fn parse_unknown_topology() {
+        assert_that!(
+            Topology::parse("sphere"),
+            is(equal_to(Err(String::from("Unknown topology 'sphere'! Expected 'plane' or 'torus'.")))));
+    }
+
+    #[test]
+    fn neighbours_on_empty_torus() {
+        assert_that!(Topology::Torus.neighbours(&Place::new(0, 0), &Dimension::new(0, 3)), is(equal_to(Vec::new())));
+        assert_that!(Topology::Torus.neighbours(&Place::new(0, 0), &Dimension::new(3, 0)), is(equal_to(Vec::new())));
+    }
+
+    #[test]
+    fn neighbours_in_the_middle() {
+        let expected = vec![
+            Place::new(4, 4), Place::new(5, 4), Place::new(6, 4),
+            Place::new(4, 5), Place::new(6, 5),
+            Place::new(4, 6), Place::new(5, 6), Place::new(6, 6),
+        ];
+
+        assert_that!(Topology::Plane.neighbours(&Place::new(5, 5), &Dimension::new(10, 10)), is(equal_to(expected.clone())));
+        assert_that!(Topology::Torus.neighbours(&Place::new(5, 5), &Dimension::new(10, 10)), is(equal_to(expected)));
+    }
+
+    #[test]
+    fn neighbours_in_the_corner_of_plane() {
+        assert_that!(
+            Topology::Plane.neighbours(&Place::new(0, 0), &Dimension::new(10, 5)),
+            is(equal_to(vec![Place::new(1, 0), Place::new(0, 1), Place::new(1, 1)])));
+    }
+
+    #[test]
+    fn neighbours_in_the_corner_of_torus() {
+        assert_that!(
+            Topology::Torus.neighbours(&Place::new(0, 0), &Dimension::new(10, 5)),
+            is(equal_to(vec![
+                Place::new(9, 4), Place::new(0, 4), Place::new(1, 4),
+                Place::new(9, 0), Place::new(1, 0),
+                Place::new(9, 1), Place::new(0, 1), Place::new(1, 1),
+            ])));
+    }
+
+    #[test]
+    fn neighbours_on_tiny_torus_are_unique() {
+        assert_that!(
+            Topology::Torus.neighbours(&Place::new(0, 0), &Dimension::new(2, 1)),
+            is(equal_to(vec![Place::new(1, 0)])));
+    }
+}
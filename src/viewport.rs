@@ -0,0 +1,465 @@
+use std::fmt;
+use crate::age::{AgeClass, ColorMode};
+use crate::cell::Cell;
+use crate::dimension::Dimension;
+use crate::place::Place;
+use crate::population::Population;
+use crate::render::Render;
+
+/// This struct describes the window of a population which is shown on the screen.
+///
+/// When zoomed out, each place of the render mode stands for a square of several places: A living
+/// cell anywhere in the square shows up as living cell, else a dying one as dying cell.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Viewport {
+    /// The place shown in the upper left corner.
+    origin: Place,
+    /// Number of characters shown per row.
+    columns: usize,
+    /// Number of characters shown per column.
+    rows: usize,
+    /// Number of places per place of the render mode in each direction.
+    zoom: usize,
+    /// How many places are packed into one character.
+    render: Render,
+    /// How living cells are coloured by their age.
+    color: ColorMode,
+    /// Whether the viewport is centered on the living cells before each generation is shown.
+    follow: bool,
+}
+
+impl Viewport {
+    /// Creates a viewport of the given number of characters showing the upper left corner.
+    pub fn new(columns: usize, rows: usize) -> Viewport {
+        Viewport { origin: Place::new(0, 0), columns: columns.max(1), rows: rows.max(1), zoom: 1, render: Render::Cells, color: ColorMode::Off, follow: false }
+    }
+
+    /// Creates a copy of this viewport with the given number of places per character.
+    pub fn with_zoom(self, zoom: usize) -> Viewport {
+        Viewport { zoom: zoom.max(1), ..self }
+    }
+
+    /// Creates a copy of this viewport which packs places into characters as given.
+    pub fn with_render(self, render: Render) -> Viewport {
+        Viewport { render, ..self }
+    }
+
+    /// Creates a copy of this viewport which colours living cells by their age as given.
+    pub fn with_color(self, color: ColorMode) -> Viewport {
+        Viewport { color, ..self }
+    }
+
+    /// Creates a copy of this viewport which follows the living cells.
+    pub fn with_follow(self) -> Viewport {
+        Viewport { follow: true, ..self }
+    }
+
+    /// How many places are packed into one character.
+    pub fn get_render(&self) -> &Render {
+        &self.render
+    }
+
+    /// Changes the number of characters shown, e.g. because the terminal was resized.
+    pub fn resize(&mut self, columns: usize, rows: usize) {
+        self.columns = columns.max(1);
+        self.rows = rows.max(1);
+    }
+
+    /// Moves the viewport by the given number of characters. Moving it by hand stops following
+    /// the living cells.
+    pub fn pan(&mut self, columns: isize, rows: isize) {
+        self.origin = Place::new(
+            offset(self.origin.get_x(), columns * self.get_places_per_column() as isize),
+            offset(self.origin.get_y(), rows * self.get_places_per_row() as isize));
+        self.follow = false;
+    }
+
+    /// Shows twice as many places per character in each direction, until one character shows the
+    /// larger side of the given size, rounded up to a power of two.
+    pub fn zoom_out(&mut self, size: &Dimension) {
+        let max_zoom = size.get_width().max(size.get_height()).max(1).next_power_of_two();
+
+        if self.zoom < max_zoom {
+            self.zoom *= 2;
+        }
+    }
+
+    /// Shows half as many places per character in each direction, but at least one.
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom / 2).max(1);
+    }
+
+    /// Starts or stops following the living cells.
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+    }
+
+    /// Moves the viewport so that the given population is shown as good as possible.
+    ///
+    /// If the viewport follows the living cells, their centroid is moved to its center. In any case
+    /// the viewport is kept inside the population space.
+    pub fn adjust_to(&mut self, population: &Population) {
+        if self.follow {
+            if let Some(centroid) = calculate_centroid(&population.get_living_places()) {
+                self.origin = Place::new(
+                    centroid.get_x().saturating_sub(self.columns * self.get_places_per_column() / 2),
+                    centroid.get_y().saturating_sub(self.rows * self.get_places_per_row() / 2));
+            }
+        }
+
+        let size = population.get_size();
+        self.origin = Place::new(
+            self.origin.get_x().min(get_max_origin(size.get_width(), self.columns, self.get_places_per_column())),
+            self.origin.get_y().min(get_max_origin(size.get_height(), self.rows, self.get_places_per_row())));
+    }
+
+    /// Number of places shown side by side in one character.
+    fn get_places_per_column(&self) -> usize {
+        self.render.get_width() * self.zoom
+    }
+
+    /// Number of places shown on top of each other in one character.
+    fn get_places_per_row(&self) -> usize {
+        self.render.get_height() * self.zoom
+    }
+
+    /// Whether the whole space of the given size is shown one place per character.
+    pub fn shows_all(&self, size: &Dimension) -> bool {
+        self.zoom == 1
+            && self.render == Render::Cells
+            && self.origin == Place::new(0, 0)
+            && self.columns >= size.get_width()
+            && self.rows >= size.get_height()
+    }
+
+    /// Renders the shown window of the population with the glyphs and frame of its theme.
+    ///
+    /// Only the frame of the theme is used if several places are packed into one character.
+    pub fn render(&self, population: &Population) -> String {
+        let size = population.get_size();
+        let theme = population.get_theme();
+        let columns = self.columns.min(
+            size.get_width().saturating_sub(self.origin.get_x()).div_ceil(self.get_places_per_column()));
+        let rows = self.rows.min(
+            size.get_height().saturating_sub(self.origin.get_y()).div_ceil(self.get_places_per_row()));
+        let line = if self.render == Render::Cells {
+            theme.generate_line(columns)
+        } else {
+            theme.generate_line_for_columns(columns)
+        };
+        let mut buf = String::new();
+
+        if theme.has_border() {
+            buf.push_str(&line);
+            buf.push('\n');
+        }
+
+        for row in 0..rows {
+            if theme.has_border() {
+                buf.push_str(theme.get_side());
+            }
+
+            for column in 0..columns {
+                let x = self.origin.get_x() + column * self.get_places_per_column();
+                let y = self.origin.get_y() + row * self.get_places_per_row();
+
+                if self.render == Render::Cells {
+                    match find_representative_cell(population, x, y, self.zoom) {
+                        Some(ref cell) if !cell.is_dead() => buf.push_str(
+                            &self.color.paint(theme.get_glyph(cell, population.get_trail()), &AgeClass::of(cell.get_age()))),
+                        Some(cell) => buf.push_str(&self.color.paint_trail(
+                            theme.get_glyph(&cell, population.get_trail()), cell.get_decay(), population.get_trail())),
+                        None => buf.push_str(theme.get_empty()),
+                    }
+                } else {
+                    buf.push_str(&self.render_squares(&self.find_living_squares(population, x, y)));
+                }
+            }
+
+            if theme.has_border() {
+                buf.push_str(theme.get_side());
+            }
+
+            buf.push('\n');
+        }
+
+        if theme.has_border() {
+            buf.push_str(&line);
+            buf.push('\n');
+        }
+
+        buf
+    }
+
+    /// Finds the living cells representing the squares of a character with the given upper left
+    /// corner, by row and column.
+    fn find_living_squares(&self, population: &Population, x: usize, y: usize) -> Vec<Vec<Option<Cell>>> {
+        (0..self.render.get_height())
+            .map(|row| (0..self.render.get_width())
+                .map(|column| {
+                    let cell = find_representative_cell(
+                        population,
+                        x + column * self.zoom,
+                        y + row * self.zoom,
+                        self.zoom);
+
+                    cell.filter(|cell| !cell.is_dead())
+                })
+                .collect())
+            .collect()
+    }
+
+    /// Renders the character showing the given squares, coloured by the age of the youngest cell.
+    fn render_squares(&self, squares: &[Vec<Option<Cell>>]) -> String {
+        let living: Vec<Vec<bool>> = squares.iter()
+            .map(|row| row.iter().map(Option::is_some).collect())
+            .collect();
+        let glyph = self.render.get_glyph(&living).to_string();
+        let youngest = squares.iter().flatten().flatten().map(Cell::get_age).min();
+
+        match youngest {
+            Some(age) => self.color.paint(&glyph, &AgeClass::of(age)),
+            None => glyph,
+        }
+    }
+}
+
+impl fmt::Display for Viewport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Viewport: {} {}x{}, Zoom: 1:{}, Follow: {}",
+            self.origin,
+            self.columns * self.get_places_per_column(),
+            self.rows * self.get_places_per_row(),
+            self.zoom,
+            if self.follow { "on" } else { "off" })
+    }
+}
+
+/// The largest origin coordinate which still fills the given number of characters, each showing
+/// the given number of places.
+fn get_max_origin(length: usize, characters: usize, places: usize) -> usize {
+    let length = length.div_ceil(places) * places;
+
+    length.saturating_sub(characters * places)
+}
+
+fn offset(coordinate: usize, delta: isize) -> usize {
+    if delta < 0 {
+        coordinate.saturating_sub(delta.unsigned_abs())
+    } else {
+        coordinate.saturating_add(delta as usize)
+    }
+}
+
+/// Finds the cell which represents the square of places with the given upper left corner: A
+/// living cell is preferred over a dying one. Places outside of the population are skipped.
+fn find_representative_cell(population: &Population, x: usize, y: usize, zoom: usize) -> Option<Cell> {
+    let size = population.get_size();
+    let mut representative: Option<Cell> = None;
+
+    for y in y..y.saturating_add(zoom).min(size.get_height()) {
+        for x in x..x.saturating_add(zoom).min(size.get_width()) {
+            match population.get_cell(&Place::new(x, y)) {
+                Some(cell) if !cell.is_dead() => return Some(cell),
+                Some(cell) => representative = Some(cell),
+                None => {},
+            }
+        }
+    }
+
+    representative
+}
+
+/// Calculates the mean place of the given places, if there are any.
+fn calculate_centroid(places: &[Place]) -> Option<Place> {
+    if places.is_empty() {
+        return None;
+    }
+
+    let x: usize = places.iter().map(Place::get_x).sum();
+    let y: usize = places.iter().map(Place::get_y).sum();
+
+    Some(Place::new(x / places.len(), y / places.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Rule;
+    use crate::theme::Theme;
+    use hamcrest::prelude::*;
+
+    fn create_population(width: usize, height: usize, places: Vec<(usize, usize)>) -> Population {
+        Population::new(
+            width,
+            height,
+            places.into_iter().map(|(x, y)| Cell::new(Place::new(x, y))).collect()).unwrap()
+    }
+
+    #[test]
+    fn render_whole_population_like_display() {
+        let population = create_population(3, 2, vec![(0, 0), (2, 1)]);
+        let sut = Viewport::new(10, 10);
+
+        assert_that!(sut.render(&population), is(equal_to(format!("{}", population))));
+        assert_that!(sut.shows_all(population.get_size()), is(true));
+    }
+
+    #[test]
+    fn render_with_theme_without_border() {
+        let population = create_population(3, 2, vec![(0, 0), (2, 1)])
+            .with_theme(Theme::ascii().without_border());
+        let sut = Viewport::new(10, 10);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("#  \n  #\n"))));
+        assert_that!(sut.render(&population), is(equal_to(format!("{}", population))));
+    }
+
+    #[test]
+    fn render_with_wide_theme() {
+        let population = create_population(2, 1, vec![(1, 0)]).with_theme(Theme::emoji());
+        let sut = Viewport::new(10, 10);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+----+\n|  🌕|\n+----+\n"))));
+    }
+
+    #[test]
+    fn render_window_of_population() {
+        let population = create_population(6, 4, vec![(3, 2), (5, 3)]);
+        let mut sut = Viewport::new(2, 2);
+        sut.pan(3, 2);
+        sut.adjust_to(&population);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+--+\n|☀ |\n|  |\n+--+\n"))));
+        assert_that!(sut.shows_all(population.get_size()), is(false));
+    }
+
+    #[test]
+    fn adjust_keeps_viewport_inside_population() {
+        let population = create_population(6, 4, Vec::new());
+        let mut sut = Viewport::new(4, 2);
+        sut.pan(10, 10);
+        sut.adjust_to(&population);
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from("Viewport: (2, 2) 4x2, Zoom: 1:1, Follow: off"))));
+    }
+
+    #[test]
+    fn render_zoomed_out_aggregates_places() {
+        let population = create_population(4, 4, vec![(0, 0), (1, 0), (3, 3)])
+            .next_generation(&Rule::parse("B/S1").unwrap());
+        let sut = Viewport::new(10, 10).with_zoom(2);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+--+\n|☀ |\n| ☼|\n+--+\n"))));
+    }
+
+    #[test]
+    fn render_half_blocks() {
+        let population = create_population(2, 3, vec![(0, 0), (1, 1), (0, 2)]);
+        let sut = Viewport::new(10, 10).with_render(Render::HalfBlocks);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+--+\n|▀▄|\n|▀ |\n+--+\n"))));
+    }
+
+    #[test]
+    fn render_braille() {
+        let population = create_population(3, 4, vec![(0, 0), (1, 1), (0, 3), (1, 3), (2, 0)]);
+        let sut = Viewport::new(10, 10).with_render(Render::Braille);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+--+\n|⣑⠁|\n+--+\n"))));
+        assert_that!(sut.shows_all(population.get_size()), is(false));
+    }
+
+    #[test]
+    fn render_braille_window() {
+        let population = create_population(8, 8, vec![(4, 4)]);
+        let mut sut = Viewport::new(1, 1).with_render(Render::Braille);
+        sut.pan(2, 1);
+        sut.adjust_to(&population);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+-+\n|⠁|\n+-+\n"))));
+        assert_that!(format!("{}", sut), is(equal_to(String::from("Viewport: (4, 4) 2x4, Zoom: 1:1, Follow: off"))));
+    }
+
+    #[test]
+    fn render_colored_by_age() {
+        let population = create_population(3, 1, vec![(0, 0), (1, 0), (2, 0)])
+            .next_generation(&Rule::parse("B/S1").unwrap());
+        let sut = Viewport::new(10, 10).with_color(ColorMode::Ansi256);
+
+        assert_that!(
+            sut.render(&population),
+            is(equal_to(String::from("+---+\n|\x1b[38;5;226m☀\x1b[0m\x1b[38;5;250m☼\x1b[0m\x1b[38;5;226m☀\x1b[0m|\n+---+\n"))));
+    }
+
+    #[test]
+    fn render_half_blocks_colored_by_youngest_cell() {
+        let population = create_population(1, 2, vec![(0, 0)])
+            .next_generation(&Rule::parse("B1/S0").unwrap());
+        let sut = Viewport::new(10, 10).with_render(Render::HalfBlocks).with_color(ColorMode::Ansi256);
+
+        assert_that!(sut.render(&population), is(equal_to(String::from("+-+\n|\x1b[38;5;118m█\x1b[0m|\n+-+\n"))));
+    }
+
+    #[test]
+    fn find_representative_cell_prefers_living_cells() {
+        let population = create_population(4, 4, vec![(0, 0), (2, 1), (2, 2)])
+            .next_generation(&Rule::parse("B/S1").unwrap());
+
+        assert_that!(find_representative_cell(&population, 0, 0, 3), is(equal_to(Some(Cell::new(Place::new(2, 1)).grow_older()))));
+        assert_that!(find_representative_cell(&population, 0, 0, 1), is(equal_to(Some(Cell::new(Place::new(0, 0)).kill()))));
+    }
+
+    #[test]
+    fn follow_centers_living_cells() {
+        let population = create_population(20, 20, vec![(10, 10), (12, 12)]);
+        let mut sut = Viewport::new(4, 4).with_follow();
+        sut.adjust_to(&population);
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from("Viewport: (9, 9) 4x4, Zoom: 1:1, Follow: on"))));
+    }
+
+    #[test]
+    fn pan_stops_following() {
+        let mut sut = Viewport::new(4, 4).with_follow();
+        sut.pan(-1, 0);
+
+        assert_that!(format!("{}", sut), is(equal_to(String::from("Viewport: (0, 0) 4x4, Zoom: 1:1, Follow: off"))));
+    }
+
+    #[test]
+    fn zoom_in_and_out() {
+        let mut sut = Viewport::new(4, 4);
+        sut.zoom_out(&Dimension::new(10, 10));
+        sut.zoom_out(&Dimension::new(10, 10));
+
+        assert_that!(sut.zoom, is(equal_to(4)));
+
+        sut.zoom_in();
+        sut.zoom_in();
+        sut.zoom_in();
+
+        assert_that!(sut.zoom, is(equal_to(1)));
+    }
+
+    #[test]
+    fn zoom_out_stops_at_population_size() {
+        let population = create_population(10, 3, vec![(0, 0), (9, 2)]);
+        let mut sut = Viewport::new(4, 4);
+
+        for _ in 0..100 {
+            sut.zoom_out(population.get_size());
+        }
+
+        assert_that!(sut.zoom, is(equal_to(16)));
+        assert_that!(sut.render(&population), is(equal_to(String::from("+-+\n|☀|\n+-+\n"))));
+    }
+
+    #[test]
+    fn calculate_centroid_of_places() {
+        assert_that!(calculate_centroid(&[Place::new(1, 2), Place::new(3, 6)]), is(equal_to(Some(Place::new(2, 4)))));
+        assert_that!(calculate_centroid(&[]), is(equal_to(None)));
+    }
+}